@@ -50,6 +50,18 @@ fn u8_fastrand(b: &mut Bencher) {
     })
 }
 
+#[bench]
+fn u8_fastrand_buffered(b: &mut Bencher) {
+    let mut rng = fastrand::BufferedRng::new(fastrand::Rng::new());
+    b.iter(|| {
+        let mut sum = 0u8;
+        for _ in 0..10_000 {
+            sum = sum.wrapping_add(rng.u8(..));
+        }
+        sum
+    })
+}
+
 #[bench]
 fn u32_wyhash(b: &mut Bencher) {
     let mut rng = WyRng::from_rng(thread_rng()).unwrap();
@@ -96,3 +108,97 @@ fn fill_naive(b: &mut Bencher) {
         bytes
     })
 }
+
+#[bench]
+fn fill_u32(b: &mut Bencher) {
+    let mut rng = fastrand::Rng::new();
+    b.iter(|| {
+        let mut values = [0u32; 367];
+        rng.fill_u32(&mut values);
+        values
+    })
+}
+
+#[bench]
+fn fill_u32_naive(b: &mut Bencher) {
+    let mut rng = fastrand::Rng::new();
+    b.iter(|| {
+        let mut values = [0u32; 367];
+        for item in &mut values {
+            *item = rng.u32(..);
+        }
+        values
+    })
+}
+
+#[bench]
+fn fill_u64(b: &mut Bencher) {
+    let mut rng = fastrand::Rng::new();
+    b.iter(|| {
+        let mut values = [0u64; 367];
+        rng.fill_u64(&mut values);
+        values
+    })
+}
+
+#[bench]
+fn fill_u64_naive(b: &mut Bencher) {
+    let mut rng = fastrand::Rng::new();
+    b.iter(|| {
+        let mut values = [0u64; 367];
+        for item in &mut values {
+            *item = rng.u64(..);
+        }
+        values
+    })
+}
+
+#[bench]
+fn fill_bools(b: &mut Bencher) {
+    let mut rng = fastrand::Rng::new();
+    b.iter(|| {
+        let mut values = [false; 367];
+        rng.fill_bools(&mut values);
+        values
+    })
+}
+
+#[bench]
+fn fill_bools_naive(b: &mut Bencher) {
+    let mut rng = fastrand::Rng::new();
+    b.iter(|| {
+        let mut values = [false; 367];
+        for item in &mut values {
+            *item = rng.bool();
+        }
+        values
+    })
+}
+
+#[bench]
+fn normal_cached(b: &mut Bencher) {
+    let mut rng = fastrand::Rng::new();
+    b.iter(|| {
+        let mut sum = 0.0;
+        for _ in 0..10_000 {
+            sum += rng.normal(0.0, 1.0);
+        }
+        sum
+    })
+}
+
+#[bench]
+fn normal_uncached(b: &mut Bencher) {
+    let mut rng = fastrand::Rng::new();
+    b.iter(|| {
+        let mut sum = 0.0;
+        for _ in 0..10_000 {
+            // Box-Muller, discarding the second value of each pair.
+            let u1 = 1.0 - rng.f64();
+            let u2 = rng.f64();
+            let radius = (-2.0 * u1.ln()).sqrt();
+            sum += radius * (2.0 * std::f64::consts::PI * u2).cos();
+        }
+        sum
+    })
+}
@@ -0,0 +1,97 @@
+//! Derive macro for the [`Random`] trait of [`fastrand`].
+//!
+//! This crate is re-exported by `fastrand` when its `derive` feature is enabled, and should not
+//! be used directly.
+//!
+//! [`Random`]: https://docs.rs/fastrand/latest/fastrand/trait.Random.html
+//! [`fastrand`]: https://docs.rs/fastrand
+
+#![forbid(unsafe_code)]
+#![warn(missing_docs, missing_debug_implementations, rust_2018_idioms)]
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::spanned::Spanned;
+use syn::{parse_macro_input, parse_quote, Data, DeriveInput, Error, Fields};
+
+/// Derives `fastrand::Random` for structs and enums.
+///
+/// See the documentation of `fastrand::Random` for details.
+#[proc_macro_derive(Random)]
+pub fn derive_random(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(mut input: DeriveInput) -> Result<TokenStream2, Error> {
+    let name = &input.ident;
+
+    let body = match &input.data {
+        Data::Struct(data) => construct(quote!(#name), &data.fields),
+        Data::Enum(data) => {
+            if data.variants.is_empty() {
+                return Err(Error::new(
+                    name.span(),
+                    "`Random` cannot be derived for enums without variants",
+                ));
+            }
+
+            let count = data.variants.len();
+            let arms = data.variants.iter().enumerate().map(|(i, variant)| {
+                let ident = &variant.ident;
+                let value = construct(quote!(#name::#ident), &variant.fields);
+                quote!(#i => #value,)
+            });
+            quote! {
+                match rng.usize(..#count) {
+                    #(#arms)*
+                    _ => ::core::unreachable!(),
+                }
+            }
+        }
+        Data::Union(data) => {
+            return Err(Error::new(
+                data.union_token.span(),
+                "`Random` cannot be derived for unions",
+            ))
+        }
+    };
+
+    for param in input.generics.type_params_mut() {
+        param.bounds.push(parse_quote!(::fastrand::Random));
+    }
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::fastrand::Random for #name #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn random<__B: ::fastrand::Backend>(rng: &mut ::fastrand::Rng<__B>) -> Self {
+                #body
+            }
+        }
+    })
+}
+
+/// Builds a value of a struct or variant with every field generated at random.
+fn construct(path: TokenStream2, fields: &Fields) -> TokenStream2 {
+    match fields {
+        Fields::Unit => path,
+        Fields::Unnamed(fields) => {
+            let values = fields
+                .unnamed
+                .iter()
+                .map(|_| quote!(::fastrand::Random::random(rng)));
+            quote!(#path(#(#values),*))
+        }
+        Fields::Named(fields) => {
+            let values = fields.named.iter().map(|field| {
+                let ident = &field.ident;
+                quote!(#ident: ::fastrand::Random::random(rng))
+            });
+            quote!(#path { #(#values),* })
+        }
+    }
+}
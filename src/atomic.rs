@@ -0,0 +1,135 @@
+//! A random number generator that can be shared between threads.
+
+use crate::{wyrand, Rng, WY_CONST_0};
+
+use core::ops::RangeBounds;
+use core::sync::atomic::{AtomicU64, Ordering};
+
+/// A random number generator that can be shared between threads.
+///
+/// Unlike [`Rng`], which is advanced through a mutable reference, `AtomicRng` advances its
+/// state with a single atomic operation and can be used through a shared reference, for example
+/// from a `static`. All threads draw from one stream, so a fixed seed determines the whole set of
+/// values produced, but the order in which threads observe them is nondeterministic. Every value
+/// is still uniformly distributed.
+///
+/// Each call takes one step of the shared stream and uses its output to seed a temporary
+/// [`Rng`], so methods that need several random numbers, such as bounded ranges, never observe
+/// values drawn by other threads. Under heavy contention the shared state becomes a bottleneck;
+/// prefer a thread-local generator when a shared stream is not needed.
+///
+/// This type requires the `atomic` feature and a target with 64-bit atomics.
+///
+/// # Example
+///
+/// ```
+/// use fastrand::AtomicRng;
+///
+/// static RNG: AtomicRng = AtomicRng::with_seed(7);
+///
+/// let handles: Vec<_> = (0..4)
+///     .map(|_| std::thread::spawn(|| RNG.u32(..100)))
+///     .collect();
+///
+/// for handle in handles {
+///     assert!(handle.join().unwrap() < 100);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct AtomicRng(AtomicU64);
+
+#[cfg(feature = "std")]
+impl Default for AtomicRng {
+    /// Initialize the `AtomicRng` from a new [`Rng`].
+    ///
+    /// This is equivalent to [`AtomicRng::new()`].
+    #[inline]
+    fn default() -> AtomicRng {
+        AtomicRng::new()
+    }
+}
+
+macro_rules! atomic_integer {
+    ($t:tt, $doc:tt) => {
+        #[doc = $doc]
+        ///
+        /// Panics if the range is empty.
+        #[inline]
+        pub fn $t(&self, range: impl RangeBounds<$t>) -> $t {
+            self.fork().$t(range)
+        }
+    };
+}
+
+impl AtomicRng {
+    /// Creates a new shared generator with the initial seed.
+    #[inline]
+    pub const fn with_seed(seed: u64) -> AtomicRng {
+        AtomicRng(AtomicU64::new(seed))
+    }
+
+    /// Creates a new shared generator seeded from [`Rng::new()`].
+    ///
+    /// With the `global` feature, the seed comes from the thread-local generator. Without it,
+    /// every generator created by this function starts from the same fixed seed.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    pub fn new() -> AtomicRng {
+        AtomicRng::with_seed(Rng::new().u64(..))
+    }
+
+    /// Reseeds the generator.
+    #[inline]
+    pub fn seed(&self, seed: u64) {
+        self.0.store(seed, Ordering::Relaxed);
+    }
+
+    /// Gives back the **current** seed that is being held by this generator.
+    #[inline]
+    pub fn get_seed(&self) -> u64 {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    /// Takes one step of the shared stream and returns a generator seeded with its output.
+    ///
+    /// This is the atomic counterpart of [`Rng::fork`], and can be used to draw several values
+    /// from the returned generator without touching the shared state again.
+    #[inline]
+    #[must_use = "this creates a new instance of `Rng`"]
+    pub fn fork(&self) -> Rng {
+        let mut state = self.0.fetch_add(WY_CONST_0, Ordering::Relaxed);
+        Rng::with_seed(wyrand(&mut state))
+    }
+
+    /// Generates a random `bool`.
+    #[inline]
+    pub fn bool(&self) -> bool {
+        self.fork().bool()
+    }
+
+    /// Generates a random `f32` in range `0..1`.
+    #[inline]
+    pub fn f32(&self) -> f32 {
+        self.fork().f32()
+    }
+
+    /// Generates a random `f64` in range `0..1`.
+    #[inline]
+    pub fn f64(&self) -> f64 {
+        self.fork().f64()
+    }
+
+    atomic_integer!(u8, "Generates a random `u8` in the given range.");
+    atomic_integer!(u16, "Generates a random `u16` in the given range.");
+    atomic_integer!(u32, "Generates a random `u32` in the given range.");
+    atomic_integer!(u64, "Generates a random `u64` in the given range.");
+    atomic_integer!(u128, "Generates a random `u128` in the given range.");
+    atomic_integer!(usize, "Generates a random `usize` in the given range.");
+    atomic_integer!(i8, "Generates a random `i8` in the given range.");
+    atomic_integer!(i16, "Generates a random `i16` in the given range.");
+    atomic_integer!(i32, "Generates a random `i32` in the given range.");
+    atomic_integer!(i64, "Generates a random `i64` in the given range.");
+    atomic_integer!(i128, "Generates a random `i128` in the given range.");
+    atomic_integer!(isize, "Generates a random `isize` in the given range.");
+}
@@ -0,0 +1,135 @@
+//! Algorithms that generate the raw random numbers used by [`Rng`](crate::Rng).
+
+use crate::wyrand;
+
+/// An algorithm that generates uniformly distributed random integers.
+///
+/// [`Rng`](crate::Rng) builds all of its methods, such as ranges, shuffling and distributions,
+/// on top of a backend, and defaults to [`Wyrand`]. Implementing this trait allows those methods
+/// to be used with a different algorithm.
+///
+/// # Example
+///
+/// ```
+/// use fastrand::{Backend, Pcg32, Rng};
+///
+/// let mut rng = Rng::from_backend(Pcg32::seed_from_u64(7));
+/// let x = rng.u32(1..=6);
+/// assert!((1..=6).contains(&x));
+/// ```
+pub trait Backend {
+    /// Creates a new backend from a 64-bit seed.
+    ///
+    /// This is used by methods that derive new generators, such as
+    /// [`Rng::fork`](crate::Rng::fork).
+    fn seed_from_u64(seed: u64) -> Self;
+
+    /// Generates a random `u32`.
+    ///
+    /// The default implementation takes the low bits of [`next_u64`](Backend::next_u64).
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    /// Generates a random `u64`.
+    fn next_u64(&mut self) -> u64;
+}
+
+/// A version of the algorithm used by the default [`Rng`](crate::Rng).
+///
+/// [`Rng::new`](crate::Rng::new) and [`Rng::with_seed`](crate::Rng::with_seed) always use the
+/// current default algorithm, which may change in a future major version. Code that depends on
+/// a reproducible sequence can pin the algorithm with
+/// [`Rng::with_algorithm`](crate::Rng::with_algorithm), so that such a change does not go
+/// unnoticed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Algorithm {
+    /// The Wyrand algorithm, as implemented by [`Wyrand`] and [`wyrand`].
+    ///
+    /// This is the current default.
+    Wyrand,
+}
+
+/// The Wyrand algorithm, which is the default backend of [`Rng`](crate::Rng).
+///
+/// Its state is a single `u64` that advances by a fixed constant on every step; see [`wyrand`]
+/// for the details of the output function.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Wyrand(pub(crate) u64);
+
+impl Backend for Wyrand {
+    #[inline]
+    fn seed_from_u64(seed: u64) -> Wyrand {
+        Wyrand(seed)
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        wyrand(&mut self.0)
+    }
+}
+
+/// The PCG32 algorithm, in its XSH RR 64/32 variant.
+///
+/// PCG32 has a 64-bit state and produces 32-bit outputs, so [`next_u64`](Backend::next_u64)
+/// combines two of them. It is slower than [`Wyrand`] for 64-bit values, but has a longer
+/// history of statistical analysis.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pcg32 {
+    state: u64,
+    increment: u64,
+}
+
+impl Pcg32 {
+    // Multiplier and default increment from the reference implementation at https://www.pcg-random.org.
+    const MULTIPLIER: u64 = 6_364_136_223_846_793_005;
+    const DEFAULT_INCREMENT: u64 = 1_442_695_040_888_963_407;
+
+    /// Creates a new generator with the given seed and stream.
+    ///
+    /// Generators with the same seed but different streams produce different sequences.
+    #[inline]
+    pub fn new(seed: u64, stream: u64) -> Pcg32 {
+        let mut pcg = Pcg32 {
+            state: 0,
+            increment: (stream << 1) | 1,
+        };
+        pcg.step();
+        pcg.state = pcg.state.wrapping_add(seed);
+        pcg.step();
+        pcg
+    }
+
+    #[inline]
+    fn step(&mut self) {
+        self.state = self
+            .state
+            .wrapping_mul(Self::MULTIPLIER)
+            .wrapping_add(self.increment);
+    }
+}
+
+impl Backend for Pcg32 {
+    #[inline]
+    fn seed_from_u64(seed: u64) -> Pcg32 {
+        Pcg32::new(seed, Self::DEFAULT_INCREMENT >> 1)
+    }
+
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        let old = self.state;
+        self.step();
+        let xorshifted = (((old >> 18) ^ old) >> 27) as u32;
+        let rot = (old >> 59) as u32;
+        xorshifted.rotate_right(rot)
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        let lo = u64::from(self.next_u32());
+        let hi = u64::from(self.next_u32());
+        (hi << 32) | lo
+    }
+}
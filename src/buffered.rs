@@ -0,0 +1,123 @@
+//! A generator that hands out the bytes of each random number one at a time.
+
+use crate::{Backend, Rng, Wyrand};
+
+use core::ops::{Bound, RangeBounds};
+
+/// A wrapper around [`Rng`] that generates small values from a buffer of random bytes.
+///
+/// Every call to [`Rng::u8`] consumes a full output of the generator. `BufferedRng` instead
+/// keeps the leftover bytes of each 64-bit output and hands them out one at a time, so eight
+/// `u8` values cost a single step of the generator. This makes a difference for workloads that
+/// draw millions of bytes, at the cost of a slightly larger generator.
+///
+/// The values produced are uniformly distributed, but they differ from the values that the
+/// wrapped [`Rng`] would produce on its own.
+///
+/// # Example
+///
+/// ```
+/// use fastrand::{BufferedRng, Rng};
+///
+/// let mut rng = BufferedRng::new(Rng::with_seed(7));
+/// let dice: Vec<u8> = (0..100).map(|_| rng.u8(1..=6)).collect();
+///
+/// assert!(dice.iter().all(|d| (1..=6).contains(d)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BufferedRng<B = Wyrand> {
+    rng: Rng<B>,
+    buffer: u64,
+    remaining: u8,
+}
+
+impl<B: Backend> BufferedRng<B> {
+    /// Creates a new buffered generator that draws from `rng`.
+    #[inline]
+    #[must_use = "this creates a new instance of `BufferedRng`"]
+    pub fn new(rng: Rng<B>) -> Self {
+        BufferedRng {
+            rng,
+            buffer: 0,
+            remaining: 0,
+        }
+    }
+
+    /// Returns a mutable reference to the wrapped generator.
+    ///
+    /// Using the wrapped generator directly does not discard the buffered bytes.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut Rng<B> {
+        &mut self.rng
+    }
+
+    /// Returns the wrapped generator, discarding the buffered bytes.
+    #[inline]
+    pub fn into_inner(self) -> Rng<B> {
+        self.rng
+    }
+
+    /// Takes the next random byte from the buffer, refilling it when it is empty.
+    #[inline]
+    fn gen_byte(&mut self) -> u8 {
+        if self.remaining == 0 {
+            self.buffer = self.rng.next_u64();
+            self.remaining = 8;
+        }
+        let byte = self.buffer as u8;
+        self.buffer >>= 8;
+        self.remaining -= 1;
+        byte
+    }
+
+    /// Generates a random `bool`.
+    #[inline]
+    pub fn bool(&mut self) -> bool {
+        self.gen_byte() % 2 == 0
+    }
+
+    /// Generates a random `u8` in the given range.
+    ///
+    /// Panics if the range is empty.
+    #[inline]
+    pub fn u8(&mut self, range: impl RangeBounds<u8>) -> u8 {
+        let panic_empty_range = || {
+            panic!(
+                "empty range: {:?}..{:?}",
+                range.start_bound(),
+                range.end_bound()
+            )
+        };
+
+        let low = match range.start_bound() {
+            Bound::Unbounded => u8::MIN,
+            Bound::Included(&x) => x,
+            Bound::Excluded(&x) => x.checked_add(1).unwrap_or_else(panic_empty_range),
+        };
+
+        let high = match range.end_bound() {
+            Bound::Unbounded => u8::MAX,
+            Bound::Included(&x) => x,
+            Bound::Excluded(&x) => x.checked_sub(1).unwrap_or_else(panic_empty_range),
+        };
+
+        if low > high {
+            panic_empty_range();
+        }
+
+        if low == u8::MIN && high == u8::MAX {
+            return self.gen_byte();
+        }
+
+        // Adapted from: https://lemire.me/blog/2016/06/30/fast-random-shuffling/
+        let n = high - low + 1;
+        let mut m = u16::from(self.gen_byte()) * u16::from(n);
+        if (m as u8) < n {
+            let t = n.wrapping_neg() % n;
+            while (m as u8) < t {
+                m = u16::from(self.gen_byte()) * u16::from(n);
+            }
+        }
+        low + (m >> 8) as u8
+    }
+}
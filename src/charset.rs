@@ -0,0 +1,39 @@
+//! Character sets for random strings.
+
+/// A set of characters that random strings are built from.
+///
+/// This is used by [`Rng::string`](crate::Rng::string).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Charset {
+    /// Characters in ranges a-z and A-Z.
+    Alphabetic,
+    /// Characters in ranges a-z, A-Z and 0-9.
+    Alphanumeric,
+    /// Characters in range a-z.
+    Lowercase,
+    /// Characters in range A-Z.
+    Uppercase,
+    /// Lowercase hexadecimal digits, in ranges 0-9 and a-f.
+    Hex,
+    /// Printable ASCII characters, in range `' '..='~'`.
+    AsciiPrintable,
+}
+
+impl Charset {
+    /// Returns the characters of this set as ASCII bytes.
+    pub(crate) fn chars(self) -> &'static [u8] {
+        match self {
+            Charset::Alphabetic => b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz",
+            Charset::Alphanumeric => {
+                b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789"
+            }
+            Charset::Lowercase => b"abcdefghijklmnopqrstuvwxyz",
+            Charset::Uppercase => b"ABCDEFGHIJKLMNOPQRSTUVWXYZ",
+            Charset::Hex => b"0123456789abcdef",
+            Charset::AsciiPrintable => {
+                b" !\"#$%&'()*+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\]^_`abcdefghijklmnopqrstuvwxyz{|}~"
+            }
+        }
+    }
+}
@@ -0,0 +1,104 @@
+//! A random picker that avoids recently chosen items.
+
+use crate::{Backend, Rng};
+
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+
+/// Picks items uniformly at random while avoiding items chosen in the last few picks.
+///
+/// Every pick is uniform among the items that were not returned by any of the previous `window`
+/// picks. If `window` is not smaller than the number of items, it is reduced to one less than
+/// the number of items, so that there is always at least one item to pick from.
+///
+/// # Example
+///
+/// ```
+/// use fastrand::{CooldownPicker, Rng};
+///
+/// let mut rng = Rng::new();
+/// let mut picker = CooldownPicker::new(vec!["C", "D", "E", "G", "A"], 2);
+///
+/// let a = *picker.pick(&mut rng);
+/// let b = *picker.pick(&mut rng);
+/// let c = *picker.pick(&mut rng);
+/// assert!(a != b && b != c && a != c);
+/// ```
+#[derive(Debug, Clone)]
+pub struct CooldownPicker<T> {
+    items: Vec<T>,
+    /// A permutation of item indices; the first `available` entries are eligible for picking.
+    order: Vec<usize>,
+    /// The position of each item index within `order`.
+    position: Vec<usize>,
+    available: usize,
+    /// Recently picked item indices, oldest first.
+    recent: VecDeque<usize>,
+    window: usize,
+}
+
+impl<T> CooldownPicker<T> {
+    /// Creates a new picker over `items` that avoids repeating any of the last `window` picks.
+    ///
+    /// Panics if `items` is empty.
+    pub fn new(items: Vec<T>, window: usize) -> CooldownPicker<T> {
+        if items.is_empty() {
+            panic!("cannot pick from an empty set of items");
+        }
+
+        let len = items.len();
+        CooldownPicker {
+            items,
+            order: (0..len).collect(),
+            position: (0..len).collect(),
+            available: len,
+            recent: VecDeque::with_capacity(window.min(len)),
+            window: window.min(len - 1),
+        }
+    }
+
+    /// Returns the items this picker chooses from.
+    #[inline]
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    /// Returns the number of recent picks that are avoided.
+    ///
+    /// This may be smaller than the window passed to [`CooldownPicker::new`] if there are not
+    /// enough items.
+    #[inline]
+    pub fn window(&self) -> usize {
+        self.window
+    }
+
+    /// Picks a random item that was not returned by any of the last [`window`] picks.
+    ///
+    /// [`window`]: CooldownPicker::window
+    pub fn pick<B: Backend>(&mut self, rng: &mut Rng<B>) -> &T {
+        let i = rng.usize(..self.available);
+        let item = self.order[i];
+
+        // Move the picked item out of the eligible prefix.
+        self.available -= 1;
+        self.swap_order(i, self.available);
+        self.recent.push_back(item);
+
+        // Release the oldest item once it falls out of the window.
+        if self.recent.len() > self.window {
+            let released = self.recent.pop_front().unwrap();
+            let p = self.position[released];
+            self.swap_order(p, self.available);
+            self.available += 1;
+        }
+
+        &self.items[item]
+    }
+
+    /// Swaps two entries of `order`, keeping `position` in sync.
+    fn swap_order(&mut self, a: usize, b: usize) {
+        self.order.swap(a, b);
+        self.position[self.order[a]] = a;
+        self.position[self.order[b]] = b;
+    }
+}
@@ -0,0 +1,115 @@
+//! Rolling dice from tabletop notation.
+
+use crate::{Backend, Rng};
+
+use core::fmt;
+
+/// The error returned by [`Rng::dice`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DiceError {
+    /// The notation is not of the form `NdM`, `dM`, `NdM+K` or `NdM-K`.
+    Malformed,
+
+    /// The dice have zero sides.
+    ZeroSides,
+
+    /// More than 1000 dice are rolled at once.
+    TooManyDice,
+
+    /// The result does not fit in an `i64`.
+    Overflow,
+}
+
+impl fmt::Display for DiceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            DiceError::Malformed => {
+                "dice notation must be of the form `NdM`, optionally followed by `+K` or `-K`"
+            }
+            DiceError::ZeroSides => "dice must have at least one side",
+            DiceError::TooManyDice => "at most 1000 dice can be rolled at once",
+            DiceError::Overflow => "dice result overflows an `i64`",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DiceError {}
+
+/// The largest number of dice that [`Rng::dice`] rolls at once.
+const MAX_DICE: u32 = 1000;
+
+/// Returns `true` if `s` is a non-empty string of ASCII digits.
+fn is_number(s: &str) -> bool {
+    !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Parses a non-empty string of ASCII digits.
+fn parse_number<T: core::str::FromStr>(s: &str) -> Result<T, DiceError> {
+    // `FromStr` for integers also accepts a leading sign, which is not valid here.
+    if !is_number(s) {
+        return Err(DiceError::Malformed);
+    }
+    s.parse().map_err(|_| DiceError::Overflow)
+}
+
+impl<B: Backend> Rng<B> {
+    /// Rolls dice described in tabletop notation and returns their total.
+    ///
+    /// The notation is `NdM`, meaning `N` dice with `M` sides each, optionally followed by a
+    /// modifier `+K` or `-K` that is added to the total. The count may be omitted, so `d20` is
+    /// the same as `1d20`, and the `d` may also be uppercase. Each die is rolled independently
+    /// with [`u32(1..=M)`](Rng::u32), and at most 1000 dice can be rolled at once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut rng = fastrand::Rng::new();
+    ///
+    /// let damage = rng.dice("3d6+2").unwrap();
+    /// assert!((5..=20).contains(&damage));
+    ///
+    /// assert!(rng.dice("3x6").is_err());
+    /// ```
+    pub fn dice(&mut self, notation: &str) -> Result<i64, DiceError> {
+        let notation = notation.trim();
+        let d = notation
+            .find(|c| c == 'd' || c == 'D')
+            .ok_or(DiceError::Malformed)?;
+        let (count, rest) = (&notation[..d], &notation[d + 1..]);
+
+        let (sides, modifier) = match rest.find(|c| c == '+' || c == '-') {
+            Some(i) => {
+                if !is_number(&rest[i + 1..]) {
+                    return Err(DiceError::Malformed);
+                }
+                // Parse the sign together with the digits so that `i64::MIN` fits.
+                let modifier: i64 = rest[i..].parse().map_err(|_| DiceError::Overflow)?;
+                (&rest[..i], modifier)
+            }
+            None => (rest, 0),
+        };
+
+        let count: u32 = if count.is_empty() {
+            1
+        } else {
+            parse_number(count)?
+        };
+        let sides: u32 = parse_number(sides)?;
+        if sides == 0 {
+            return Err(DiceError::ZeroSides);
+        }
+        if count > MAX_DICE {
+            return Err(DiceError::TooManyDice);
+        }
+
+        let mut total = modifier;
+        for _ in 0..count {
+            total = total
+                .checked_add(i64::from(self.u32(1..=sides)))
+                .ok_or(DiceError::Overflow)?;
+        }
+        Ok(total)
+    }
+}
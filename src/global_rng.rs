@@ -1,9 +1,11 @@
 //! A global, thread-local random number generator.
 
-use crate::Rng;
+use crate::{Charset, DiceError, Rng, FALLBACK_SEED};
 
 use std::cell::Cell;
+use std::collections::VecDeque;
 use std::ops::RangeBounds;
+use std::string::String;
 use std::vec::Vec;
 
 // Chosen by fair roll of the dice.
@@ -21,21 +23,43 @@ impl Default for Rng {
 
 impl Rng {
     /// Creates a new random number generator.
+    ///
+    /// The generator uses the current default [`Algorithm`](crate::Algorithm). It is forked
+    /// from the thread-local generator, which is seeded from the same source as
+    /// [`from_entropy`](Rng::from_entropy), so this is the cheaper way to get an unpredictable
+    /// generator.
     #[inline]
     pub fn new() -> Rng {
-        try_with_rng(Rng::fork).unwrap_or_else(|_| Rng::with_seed(0x4d595df4d0f33173))
+        try_with_rng(Rng::fork).unwrap_or_else(|_| Rng::with_seed(FALLBACK_SEED))
+    }
+
+    /// Creates a new random number generator seeded directly from the source of entropy.
+    ///
+    /// This reads a fresh seed on every call, from the operating system with the `getrandom`
+    /// feature, or otherwise by hashing the current time and thread ID, the same way the
+    /// thread-local generator is seeded. Use it to make clear at the call site that the
+    /// generator is meant to be unpredictable, as opposed to [`with_seed`](Rng::with_seed).
+    ///
+    /// On WebAssembly targets without the `js` or `getrandom` feature there is no source of
+    /// entropy, and this falls back to [`Rng::new()`].
+    ///
+    /// This requires the `global` feature, which provides the entropy source.
+    #[cfg_attr(docsrs, doc(cfg(feature = "global")))]
+    #[inline]
+    pub fn from_entropy() -> Rng {
+        random_seed().map_or_else(Rng::new, Rng::with_seed)
     }
 }
 
 std::thread_local! {
-    static RNG: Cell<Rng> = Cell::new(Rng(random_seed().unwrap_or(DEFAULT_RNG_SEED)));
+    static RNG: Cell<Rng> = Cell::new(Rng::with_seed(random_seed().unwrap_or(DEFAULT_RNG_SEED)));
 }
 
 /// Run an operation with the current thread-local generator.
 #[inline]
 fn with_rng<R>(f: impl FnOnce(&mut Rng) -> R) -> R {
     RNG.with(|rng| {
-        let current = rng.replace(Rng(0));
+        let current = rng.replace(Rng::with_seed(0));
 
         let mut restore = RestoreOnDrop { rng, current };
 
@@ -47,7 +71,7 @@ fn with_rng<R>(f: impl FnOnce(&mut Rng) -> R) -> R {
 #[inline]
 fn try_with_rng<R>(f: impl FnOnce(&mut Rng) -> R) -> Result<R, std::thread::AccessError> {
     RNG.try_with(|rng| {
-        let current = rng.replace(Rng(0));
+        let current = rng.replace(Rng::with_seed(0));
 
         let mut restore = RestoreOnDrop { rng, current };
 
@@ -63,7 +87,7 @@ struct RestoreOnDrop<'a> {
 
 impl Drop for RestoreOnDrop<'_> {
     fn drop(&mut self) {
-        self.rng.set(Rng(self.current.0));
+        self.rng.set(self.current.clone());
     }
 }
 
@@ -73,6 +97,21 @@ pub fn seed(seed: u64) {
     with_rng(|r| r.seed(seed));
 }
 
+/// Reinitializes the thread-local generator from fresh entropy.
+///
+/// This uses the same entropy source as the initialization of the thread-local generator, and
+/// is useful to make sure that processes created with `fork()` do not continue the same
+/// sequence. Unlike [`seed`], which sets a fixed value, the new seed is unpredictable.
+///
+/// On WebAssembly targets without the `js` or `getrandom` feature there is no source of
+/// entropy, and the generator is left unchanged.
+#[inline]
+pub fn reseed() {
+    if let Some(seed) = random_seed() {
+        with_rng(|r| r.seed(seed));
+    }
+}
+
 /// Gives back **current** seed that is being held by the thread-local generator.
 #[inline]
 pub fn get_seed() -> u64 {
@@ -85,6 +124,57 @@ pub fn bool() -> bool {
     with_rng(|r| r.bool())
 }
 
+/// Generates a random `bool` that is `true` with probability `p`.
+///
+/// If `p <= 0.0` or `p` is NaN the result is always `false`, and if `p >= 1.0` it is always
+/// `true`.
+#[inline]
+pub fn bool_with_probability(p: f64) -> bool {
+    with_rng(|r| r.bool_with_probability(p))
+}
+
+/// Generates a random `bool` that is `true` with probability `numerator / denominator`.
+///
+/// If `numerator >= denominator` the result is always `true`.
+///
+/// Panics if `denominator` is zero.
+#[inline]
+pub fn ratio(numerator: u32, denominator: u32) -> bool {
+    with_rng(|r| r.ratio(numerator, denominator))
+}
+
+/// Generates `-1.0` or `1.0` with equal probability.
+#[inline]
+pub fn sign_f64() -> f64 {
+    with_rng(|r| r.sign_f64())
+}
+
+/// Generates `-1` or `1` with equal probability.
+#[inline]
+pub fn sign_i32() -> i32 {
+    with_rng(|r| r.sign_i32())
+}
+
+/// Generates a random color as red, green and blue channels.
+#[inline]
+pub fn rgb() -> (u8, u8, u8) {
+    with_rng(|r| r.rgb())
+}
+
+/// Generates a random color as red, green, blue and alpha channels.
+#[inline]
+pub fn rgba() -> (u8, u8, u8, u8) {
+    with_rng(|r| r.rgba())
+}
+
+/// Generates a random color as hue, saturation and value.
+///
+/// The hue is in degrees in range `0..360`, and saturation and value are in range `0..1`.
+#[inline]
+pub fn hsv() -> (f64, f64, f64) {
+    with_rng(|r| r.hsv())
+}
+
 /// Generates a random `char` in ranges a-z and A-Z.
 #[inline]
 pub fn alphabetic() -> char {
@@ -97,18 +187,78 @@ pub fn alphanumeric() -> char {
     with_rng(|r| r.alphanumeric())
 }
 
+/// Randomly changes the case of an ASCII letter.
+///
+/// Other characters are returned unchanged.
+#[inline]
+pub fn random_case(c: char) -> char {
+    with_rng(|r| r.random_case(c))
+}
+
+/// Chooses a random `char` from the characters of `set`.
+///
+/// Panics if `set` is empty.
+#[inline]
+pub fn char_from(set: &str) -> char {
+    with_rng(|r| r.char_from(set))
+}
+
+/// Generates a random printable ASCII `char` in range `' '..='~'`.
+#[inline]
+pub fn ascii_printable() -> char {
+    with_rng(|r| r.ascii_printable())
+}
+
+/// Generates a random string of `len` printable ASCII characters.
+#[inline]
+pub fn ascii_printable_string(len: usize) -> String {
+    with_rng(|r| r.ascii_printable_string(len))
+}
+
 /// Generates a random `char` in range a-z.
 #[inline]
 pub fn lowercase() -> char {
     with_rng(|r| r.lowercase())
 }
 
+/// Generates a random string of `len` characters in range a-z.
+#[inline]
+pub fn lowercase_string(len: usize) -> String {
+    with_rng(|r| r.lowercase_string(len))
+}
+
 /// Generates a random `char` in range A-Z.
 #[inline]
 pub fn uppercase() -> char {
     with_rng(|r| r.uppercase())
 }
 
+/// Generates a random string of `len` characters in range A-Z.
+#[inline]
+pub fn uppercase_string(len: usize) -> String {
+    with_rng(|r| r.uppercase_string(len))
+}
+
+/// Generates a random string of `len` characters from the given character set.
+#[inline]
+pub fn string(len: usize, charset: Charset) -> String {
+    with_rng(|r| r.string(len, charset))
+}
+
+/// Generates a random string of `len` lowercase hexadecimal digits.
+#[inline]
+pub fn hex_string(len: usize) -> String {
+    with_rng(|r| r.hex_string(len))
+}
+
+/// Generates a random semantic version string, such as `1.4.2` or `0.3.0-beta.2+build.7`.
+///
+/// Pre-release and build metadata are each included with probability 1/2.
+#[inline]
+pub fn semver() -> String {
+    with_rng(|r| r.semver())
+}
+
 /// Choose an item from an iterator at random.
 ///
 /// This function may have an unexpected result if the `len()` property of the
@@ -123,6 +273,38 @@ where
     with_rng(|r| r.choice(iter))
 }
 
+/// Chooses a random index into `weights`, with probability proportional to each weight.
+///
+/// Returns `None` if `weights` is empty, if any weight is negative or not finite, or if the
+/// weights sum to zero.
+#[inline]
+pub fn weighted_index(weights: &[f64]) -> Option<usize> {
+    with_rng(|r| r.weighted_index(weights))
+}
+
+/// Chooses a random element of a slice and returns it along with its index.
+///
+/// Returns `None` if the slice is empty.
+#[inline]
+pub fn choose_with_index<T>(slice: &[T]) -> Option<(usize, &T)> {
+    with_rng(|r| r.choose_with_index(slice))
+}
+
+/// Chooses an item at random, with probability proportional to its weight.
+///
+/// Returns `None` if `items` is empty, if `items` and `weights` have different lengths, if
+/// any weight is negative or not finite, or if the weights sum to zero.
+#[inline]
+pub fn weighted_choice<'a, T>(items: &'a [T], weights: &[f64]) -> Option<&'a T> {
+    with_rng(|r| r.weighted_choice(items, weights))
+}
+
+/// Rolls dice described in tabletop notation, such as `3d6+2`, and returns their total.
+#[inline]
+pub fn dice(notation: &str) -> Result<i64, DiceError> {
+    with_rng(|r| r.dice(notation))
+}
+
 /// Generates a random digit in the given `base`.
 ///
 /// Digits are represented by `char`s in ranges 0-9 and a-z.
@@ -133,18 +315,50 @@ pub fn digit(base: u32) -> char {
     with_rng(|r| r.digit(base))
 }
 
+/// Generates a random string of `n` digits in the given `base`.
+///
+/// Panics if the base is zero or greater than 36.
+#[inline]
+pub fn digits(base: u32, n: usize) -> String {
+    with_rng(|r| r.digits(base, n))
+}
+
 /// Shuffles a slice randomly.
 #[inline]
 pub fn shuffle<T>(slice: &mut [T]) {
     with_rng(|r| r.shuffle(slice))
 }
 
+/// Shuffles a [`VecDeque`] randomly.
+#[inline]
+pub fn shuffle_deque<T>(deque: &mut VecDeque<T>) {
+    with_rng(|r| r.shuffle_deque(deque))
+}
+
+/// Generates a random permutation of `0..n`.
+#[inline]
+pub fn permutation(n: usize) -> Vec<usize> {
+    with_rng(|r| r.permutation(n))
+}
+
 /// Fill a byte slice with random data.
 #[inline]
 pub fn fill(slice: &mut [u8]) {
     with_rng(|r| r.fill(slice))
 }
 
+/// Generates an array of random bytes.
+#[inline]
+pub fn fill_array<const N: usize>() -> [u8; N] {
+    with_rng(|r| r.fill_array())
+}
+
+/// Generates a vector of `n` random bytes.
+#[inline]
+pub fn bytes(n: usize) -> Vec<u8> {
+    with_rng(|r| r.bytes(n))
+}
+
 macro_rules! integer {
     ($t:tt, $doc:tt) => {
         #[doc = $doc]
@@ -171,6 +385,14 @@ integer!(usize, "Generates a random `usize` in the given range.");
 integer!(isize, "Generates a random `isize` in the given range.");
 integer!(char, "Generates a random `char` in the given range.");
 
+/// Generates a random multiple of `n` in the given range.
+///
+/// Panics if `n` is zero or if the range contains no multiples of `n`.
+#[inline]
+pub fn multiple_of(n: u64, range: impl RangeBounds<u64>) -> u64 {
+    with_rng(|r| r.multiple_of(n, range))
+}
+
 /// Generates a random `f32` in range `0..1`.
 pub fn f32() -> f32 {
     with_rng(|r| r.f32())
@@ -181,7 +403,66 @@ pub fn f64() -> f64 {
     with_rng(|r| r.f64())
 }
 
+/// Generates a random `f32` in the given range.
+///
+/// Panics if the range is empty, or if a bound is NaN or an included bound is infinite.
+pub fn f32_range(range: impl RangeBounds<f32>) -> f32 {
+    with_rng(|r| r.f32_range(range))
+}
+
+/// Generates a random `f64` in the given range.
+///
+/// Panics if the range is empty, or if a bound is NaN or an included bound is infinite.
+pub fn f64_range(range: impl RangeBounds<f64>) -> f64 {
+    with_rng(|r| r.f64_range(range))
+}
+
+/// Generates a random `f64` from the exponential distribution with the given rate `lambda`.
+///
+/// Panics if `lambda` is not positive.
+pub fn exp(lambda: f64) -> f64 {
+    with_rng(|r| r.exp(lambda))
+}
+
+/// Generates a random `u64` from the geometric distribution with success probability `p`.
+///
+/// The result is the number of failed trials before the first success.
+///
+/// Panics if `p` is not positive.
+pub fn geometric(p: f64) -> u64 {
+    with_rng(|r| r.geometric(p))
+}
+
+/// Generates a random `f64` from the normal distribution with the given mean and standard
+/// deviation.
+///
+/// Panics if `std_dev` is negative or NaN.
+pub fn normal(mean: f64, std_dev: f64) -> f64 {
+    with_rng(|r| r.normal(mean, std_dev))
+}
+
+/// Generates a random angle in radians, in range `0..2π`.
+#[inline]
+pub fn angle() -> f64 {
+    with_rng(|r| r.angle())
+}
+
+/// Generates a random point uniformly distributed on the unit circle.
+#[inline]
+pub fn unit_vector_2d() -> (f64, f64) {
+    with_rng(|r| r.unit_vector_2d())
+}
+
+/// Generates a random point uniformly distributed on the surface of the unit sphere.
+#[inline]
+pub fn unit_vector_3d() -> (f64, f64, f64) {
+    with_rng(|r| r.unit_vector_3d())
+}
+
 /// Collects `amount` values at random from the iterable into a vector.
+///
+/// The iterable is consumed in a single pass using reservoir sampling, so its length does not
+/// need to be known in advance.
 pub fn choose_multiple<I: IntoIterator>(source: I, amount: usize) -> Vec<I::Item> {
     with_rng(|rng| rng.choose_multiple(source, amount))
 }
@@ -193,19 +474,33 @@ pub fn choose_multiple<I: IntoIterator>(source: I, amount: usize) -> Vec<I::Item
 fn random_seed() -> Option<u64> {
     use std::collections::hash_map::DefaultHasher;
     use std::hash::{Hash, Hasher};
+    use std::sync::atomic::{AtomicUsize, Ordering};
     use std::thread;
     use std::time::Instant;
 
+    #[cfg(feature = "getrandom")]
+    {
+        let mut seed = [0u8; 8];
+        if getrandom::getrandom(&mut seed).is_ok() {
+            return Some(u64::from_ne_bytes(seed));
+        }
+    }
+
+    // The clock may not advance between two calls on the same thread, so also mix in a
+    // counter to keep the seeds distinct.
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+
     let mut hasher = DefaultHasher::new();
     Instant::now().hash(&mut hasher);
     thread::current().id().hash(&mut hasher);
+    CALLS.fetch_add(1, Ordering::Relaxed).hash(&mut hasher);
     Some(hasher.finish())
 }
 
 #[cfg(all(
     any(target_arch = "wasm32", target_arch = "wasm64"),
     target_os = "unknown",
-    feature = "js"
+    any(feature = "js", feature = "getrandom")
 ))]
 fn random_seed() -> Option<u64> {
     // TODO(notgull): Failures should be logged somewhere.
@@ -217,7 +512,7 @@ fn random_seed() -> Option<u64> {
 #[cfg(all(
     any(target_arch = "wasm32", target_arch = "wasm64"),
     target_os = "unknown",
-    not(feature = "js")
+    not(any(feature = "js", feature = "getrandom"))
 ))]
 fn random_seed() -> Option<u64> {
     None
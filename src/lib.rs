@@ -81,8 +81,37 @@
 //! - `std` (enabled by default): Enables the `std` library. This is required for the global
 //!   generator and global entropy. Without this feature, [`Rng`] can only be instantiated using
 //!   the [`with_seed`](Rng::with_seed) method.
+//! - `global` (enabled by default): Enables the global thread-local generator and the free
+//!   functions that use it, such as `fastrand::u32()` and `fastrand::seed()`. Disabling it
+//!   removes all thread-local state and the clock and thread ID lookups used to seed it, leaving
+//!   only the explicit [`Rng`] API. Without it, [`Rng::new()`] has no source of entropy and
+//!   always starts from the same fixed seed, so prefer [`Rng::with_seed`] with a seed of your own.
+//! - `alloc` (enabled by `std`): Enables the `alloc` library. This is required for methods that
+//!   return collections, such as [`choose_multiple`](Rng::choose_multiple). The rest of [`Rng`]
+//!   only depends on `core`, so the crate can be used on `no_std` targets by disabling default
+//!   features.
+//! - `atomic`: Enables [`AtomicRng`], a generator that can be shared between threads. It
+//!   requires a target with 64-bit atomics.
+//! - `rand_core`: Implements [`RngCore`] and [`SeedableRng`] for [`Rng`], so that it can be used
+//!   as the generator for the distributions and utilities of the [`rand`] ecosystem.
+//! - `bytemuck`: Enables [`fill_pod`](Rng::fill_pod), which fills slices of any
+//!   [`Pod`](https://docs.rs/bytemuck/1/bytemuck/trait.Pod.html) type with random bytes.
+//! - `serde`: Implements `Serialize` and `Deserialize` for [`Rng`]. The generator is serialized as
+//!   a single integer holding its current state, so a deserialized generator continues where the
+//!   original left off, except for the second value of a pending pair from
+//!   [`normal`](Rng::normal), which is not serialized.
+//! - `getrandom`: Seeds the global generator, and therefore [`Rng::new()`], with bytes from
+//!   the operating system's random number generator via the [`getrandom`] crate, instead of
+//!   hashing the current time and thread ID. If the operating system fails to provide them, the
+//!   default scheme is used. This only has an effect together with the `std` feature. The
+//!   generator is still not cryptographically secure.
+//! - `derive`: Enables `#[derive(Random)]` for implementing the [`Random`] trait on structs and
+//!   enums. This feature requires Rust 1.61 or later, the minimum supported version of the
+//!   `syn` 2 dependency of the derive macro.
 //! - `js`: Assumes that WebAssembly targets are being run in a JavaScript environment. See the
-//!   [WebAssembly Notes](#webassembly-notes) section for more information.
+//!   [WebAssembly Notes](#webassembly-notes) section for more information. This feature
+//!   enables the `getrandom` feature, so on other targets the global generator is seeded
+//!   through [`getrandom`] as well.
 //!
 //! # WebAssembly Notes
 //!
@@ -95,8 +124,15 @@
 //! entropy sources and seed the global RNG. If the `js` feature is not enabled, the global RNG will
 //! use a predefined seed.
 //!
+//! Seeding on these targets never reads the clock, so no `Instant` shim such as the `instant`
+//! crate is needed. With the `js` feature, the seed is read once per thread from
+//! `crypto.getRandomValues` through [`getrandom`]'s JavaScript backend.
+//!
 //! [`fastrand-contrib`]: https://crates.io/crates/fastrand-contrib
 //! [`getrandom`]: https://crates.io/crates/getrandom
+//! [`rand`]: https://crates.io/crates/rand
+//! [`RngCore`]: https://docs.rs/rand_core/0.6/rand_core/trait.RngCore.html
+//! [`SeedableRng`]: https://docs.rs/rand_core/0.6/rand_core/trait.SeedableRng.html
 
 #![no_std]
 #![cfg_attr(docsrs, feature(doc_cfg))]
@@ -115,48 +151,345 @@ extern crate alloc;
 extern crate std;
 
 use core::convert::{TryFrom, TryInto};
+use core::fmt;
+use core::hash::{BuildHasher, Hasher};
+use core::num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize};
 use core::ops::{Bound, RangeBounds};
+use core::str::FromStr;
+use core::time::Duration;
 
+#[cfg(feature = "alloc")]
+use alloc::collections::BTreeMap;
+#[cfg(feature = "alloc")]
+use alloc::collections::BTreeSet;
+#[cfg(feature = "alloc")]
+use alloc::collections::VecDeque;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
-
 #[cfg(feature = "std")]
-#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+use std::collections::BinaryHeap;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+#[cfg(feature = "global")]
+#[cfg_attr(docsrs, doc(cfg(feature = "global")))]
 mod global_rng;
 
-#[cfg(feature = "std")]
+#[cfg(feature = "global")]
 pub use global_rng::*;
 
+mod backend;
+mod buffered;
+mod dice;
+mod random;
+mod range;
+mod slice;
+mod stream;
+
+pub use backend::{Algorithm, Backend, Pcg32, Wyrand};
+pub use buffered::BufferedRng;
+pub use dice::DiceError;
+pub use random::Random;
+pub use range::RandomRange;
+pub use slice::SliceExt;
+pub use stream::RngStream;
+
+#[cfg(feature = "derive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
+pub use fastrand_derive::Random;
+
+#[cfg(feature = "atomic")]
+mod atomic;
+
+#[cfg(feature = "atomic")]
+#[cfg_attr(docsrs, doc(cfg(feature = "atomic")))]
+pub use atomic::AtomicRng;
+
+#[cfg(feature = "alloc")]
+mod charset;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use charset::Charset;
+
+#[cfg(feature = "alloc")]
+mod cooldown;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use cooldown::CooldownPicker;
+
+#[cfg(feature = "alloc")]
+mod mixture;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use mixture::{Component, Mixture};
+
+#[cfg(feature = "alloc")]
+mod weighted;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use weighted::{WeightedError, WeightedIndex};
+
+// Constants for WyRand taken from: https://github.com/wangyi-fudan/wyhash/blob/master/wyhash.h#L151
+// Updated for the final v4.2 implementation with improved constants for better entropy output.
+const WY_CONST_0: u64 = 0x2d35_8dcc_aa6c_78a5;
+const WY_CONST_1: u64 = 0x8bb8_4b93_962e_acc9;
+
+/// The seed of [`Rng::new()`] when there is no thread-local generator to fork from.
+#[cfg(feature = "std")]
+const FALLBACK_SEED: u64 = 0x4d595df4d0f33173;
+
 /// A random number generator.
+///
+/// The raw random numbers are generated by a [`Backend`], which is [`Wyrand`] by default. Methods
+/// that depend on the state of Wyrand, such as [`seed`](Rng::seed) or [`jump`](Rng::jump), are
+/// only available for the default backend.
+///
+/// Besides the state of its backend, the generator caches the second value produced by each
+/// round of [`normal`](Rng::normal). The cache is cleared when the state is changed with
+/// [`seed`](Rng::seed), [`jump`](Rng::jump) or [`rewind`](Rng::rewind). It is not included in
+/// the serialized forms of the generator: [`to_bytes`](Rng::to_bytes), the [`Display`] output
+/// and the `serde` representation only hold the backend state. A generator restored from them
+/// after an odd number of calls to `normal` compares unequal to the original, and its next
+/// `normal` value differs; all other methods produce the same values.
+///
+/// [`Display`]: fmt::Display
 #[derive(Debug, PartialEq, Eq)]
-pub struct Rng(u64);
+pub struct Rng<B = Wyrand>(B, Spare);
+
+/// The bits of the cached normal value of an [`Rng`], or [`Spare::NONE`].
+///
+/// Storing the bits instead of an `Option<f64>` keeps the default generator at 16 bytes, which
+/// matters because the thread-local generator is copied in and out on every call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Spare(u64);
+
+impl Spare {
+    /// A NaN bit pattern, which Box-Muller never produces.
+    const NONE: Spare = Spare(u64::MAX);
+
+    #[cfg(feature = "std")]
+    #[inline]
+    fn take(&mut self) -> Option<f64> {
+        let bits = core::mem::replace(self, Spare::NONE).0;
+        if bits == Spare::NONE.0 {
+            None
+        } else {
+            Some(f64::from_bits(bits))
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    fn set(&mut self, value: f64) {
+        self.0 = value.to_bits();
+    }
+}
 
-impl Clone for Rng {
+impl<B: Clone> Clone for Rng<B> {
     /// Clones the generator by creating a new generator with the same seed.
-    fn clone(&self) -> Rng {
-        Rng::with_seed(self.0)
+    fn clone(&self) -> Rng<B> {
+        Rng(self.0.clone(), self.1)
     }
 }
 
+#[cfg(all(feature = "std", not(feature = "global")))]
+impl Default for Rng {
+    /// Creates a generator with a fixed seed.
+    ///
+    /// This is equivalent to [`Rng::new()`].
+    #[inline]
+    fn default() -> Rng {
+        Rng::new()
+    }
+}
+
+#[cfg(all(feature = "std", not(feature = "global")))]
 impl Rng {
+    /// Creates a new random number generator.
+    ///
+    /// Without the `global` feature there is no source of entropy, so every generator created
+    /// by this function starts from the same fixed seed.
+    #[inline]
+    pub fn new() -> Rng {
+        Rng::with_seed(FALLBACK_SEED)
+    }
+}
+
+impl fmt::Display for Rng {
+    /// Formats the current state of the generator as a decimal integer.
+    ///
+    /// The output can be parsed back into a generator with the same state with [`str::parse`].
+    /// The value cached by [`normal`](Rng::normal) is not included; see [`Rng`].
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0 .0, f)
+    }
+}
+
+impl FromStr for Rng {
+    type Err = ParseRngError;
+
+    /// Parses a generator state written as a decimal integer, or as a hexadecimal integer with a
+    /// `0x` prefix.
+    fn from_str(s: &str) -> Result<Rng, ParseRngError> {
+        let state = if s.starts_with("0x") || s.starts_with("0X") {
+            u64::from_str_radix(&s[2..], 16)
+        } else {
+            s.parse()
+        };
+        state.map(Rng::with_seed).map_err(|_| ParseRngError(()))
+    }
+}
+
+/// An error returned when parsing an [`Rng`] from a string fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseRngError(());
+
+impl fmt::Display for ParseRngError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid generator state: expected a 64-bit unsigned integer")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseRngError {}
+
+#[cfg(feature = "rand_core")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand_core")))]
+impl rand_core::RngCore for Rng {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.gen_u32()
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.gen_u64()
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.fill(dest)
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill(dest);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "rand_core")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand_core")))]
+impl rand_core::SeedableRng for Rng {
+    type Seed = [u8; 8];
+
+    /// Creates a new generator from a little-endian `u64` seed.
+    #[inline]
+    fn from_seed(seed: [u8; 8]) -> Rng {
+        Rng::with_seed(u64::from_le_bytes(seed))
+    }
+
+    /// Creates a new generator with the given seed.
+    ///
+    /// This is equivalent to [`Rng::with_seed`].
+    #[inline]
+    fn seed_from_u64(seed: u64) -> Rng {
+        Rng::with_seed(seed)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl serde::Serialize for Rng {
+    /// Serializes the current state of the generator as a single `u64`.
+    ///
+    /// The value cached by [`normal`](Rng::normal) is not included; see [`Rng`].
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(self.0 .0)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> serde::Deserialize<'de> for Rng {
+    /// Deserializes a generator from the `u64` state written by its `Serialize` implementation.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Rng, D::Error> {
+        <u64 as serde::Deserialize<'de>>::deserialize(deserializer).map(Rng::with_seed)
+    }
+}
+
+/// Performs one round of the Wyrand algorithm on `state`, returning a random `u64`.
+///
+/// This is the exact step used by [`Rng`]: the state is advanced by a fixed odd constant, then
+/// the new state is multiplied with a mixed copy of itself into a 128-bit product whose halves
+/// are folded together with XOR. It can be used as a building block for custom generators.
+///
+/// # Example
+///
+/// ```
+/// let mut state = 7;
+/// let mut rng = fastrand::Rng::with_seed(7);
+///
+/// assert_eq!(fastrand::wyrand(&mut state), rng.u64(..));
+/// assert_eq!(state, rng.get_seed());
+/// ```
+#[inline]
+pub fn wyrand(state: &mut u64) -> u64 {
+    let (s, out) = wyrand_const(*state);
+    *state = s;
+    out
+}
+
+/// Performs one round of the Wyrand algorithm in a `const` context.
+///
+/// This is the same step as [`wyrand`], but takes the state by value and returns the new state
+/// together with the random `u64`, so that it can be used to compute values at compile time.
+///
+/// # Example
+///
+/// ```
+/// const fn table() -> [u64; 4] {
+///     let mut table = [0; 4];
+///     let mut state = 42;
+///     let mut i = 0;
+///     while i < table.len() {
+///         let (s, x) = fastrand::wyrand_const(state);
+///         state = s;
+///         table[i] = x;
+///         i += 1;
+///     }
+///     table
+/// }
+///
+/// const TABLE: [u64; 4] = table();
+///
+/// let mut rng = fastrand::Rng::with_seed(42);
+/// assert_eq!(TABLE[0], rng.u64(..));
+/// ```
+#[inline]
+pub const fn wyrand_const(state: u64) -> (u64, u64) {
+    let s = state.wrapping_add(WY_CONST_0);
+    let t = (s as u128) * ((s ^ WY_CONST_1) as u128);
+    (s, (t as u64) ^ (t >> 64) as u64)
+}
+
+impl<B: Backend> Rng<B> {
     /// Generates a random `u32`.
     #[inline]
     fn gen_u32(&mut self) -> u32 {
-        self.gen_u64() as u32
+        self.0.next_u32()
     }
 
     /// Generates a random `u64`.
     #[inline]
     fn gen_u64(&mut self) -> u64 {
-        // Constants for WyRand taken from: https://github.com/wangyi-fudan/wyhash/blob/master/wyhash.h#L151
-        // Updated for the final v4.2 implementation with improved constants for better entropy output.
-        const WY_CONST_0: u64 = 0x2d35_8dcc_aa6c_78a5;
-        const WY_CONST_1: u64 = 0x8bb8_4b93_962e_acc9;
-
-        let s = self.0.wrapping_add(WY_CONST_0);
-        self.0 = s;
-        let t = u128::from(s) * u128::from(s ^ WY_CONST_1);
-        (t as u64) ^ (t >> 64) as u64
+        self.0.next_u64()
     }
 
     /// Generates a random `u128`.
@@ -218,6 +551,92 @@ impl Rng {
         }
         hi
     }
+
+    /// Generates a random index into `weights`, with probability proportional to each weight.
+    ///
+    /// Returns `None` if `weights` is empty, contains a negative or non-finite weight, or sums
+    /// to zero.
+    fn gen_weighted_index<W: Copy + Into<f64>>(&mut self, weights: &[W]) -> Option<usize> {
+        let mut total = 0.0;
+        for &w in weights {
+            let w = w.into();
+            if w < 0.0 || !w.is_finite() {
+                return None;
+            }
+            total += w;
+        }
+        if total <= 0.0 || !total.is_finite() {
+            return None;
+        }
+
+        let target = self.f64() * total;
+        let mut cumulative = 0.0;
+        let mut last = None;
+        for (i, &w) in weights.iter().enumerate() {
+            let w = w.into();
+            if w > 0.0 {
+                cumulative += w;
+                if target < cumulative {
+                    return Some(i);
+                }
+                last = Some(i);
+            }
+        }
+
+        // Rounding may leave `target` just past the final bucket.
+        last
+    }
+}
+
+/// Converts a non-negative number of nanoseconds into a [`Duration`], saturating on overflow.
+fn duration_from_nanos_f64(nanos: f64) -> Duration {
+    const NANOS_PER_SEC: f64 = 1_000_000_000.0;
+
+    let secs = nanos / NANOS_PER_SEC;
+    if secs >= u64::MAX as f64 {
+        return Duration::new(u64::MAX, 999_999_999);
+    }
+    if secs <= 0.0 {
+        return Duration::new(0, 0);
+    }
+
+    let whole = secs as u64;
+    let subsec = ((nanos - whole as f64 * NANOS_PER_SEC) as u32).min(999_999_999);
+    Duration::new(whole, subsec)
+}
+
+/// An item kept by weighted reservoir sampling, together with its key.
+///
+/// The ordering is reversed, so that a [`BinaryHeap`] of these has the smallest key on top.
+#[cfg(feature = "std")]
+struct KeyedItem<T> {
+    key: f64,
+    item: T,
+}
+
+#[cfg(feature = "std")]
+impl<T> PartialEq for KeyedItem<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> Eq for KeyedItem<T> {}
+
+#[cfg(feature = "std")]
+impl<T> PartialOrd for KeyedItem<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> Ord for KeyedItem<T> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        // Keys are never NaN.
+        other.key.partial_cmp(&self.key).unwrap()
+    }
 }
 
 /// Computes `(a * b) >> 32`.
@@ -246,179 +665,1726 @@ fn mul_high_u128(a: u128, b: u128) -> u128 {
 }
 
 macro_rules! rng_integer {
-    ($t:tt, $unsigned_t:tt, $gen:tt, $mod:tt, $doc:tt) => {
+    ($t:tt, $inclusive:ident, $unsigned_t:tt, $gen:tt, $mod:tt, $doc:tt) => {
         #[doc = $doc]
         ///
         /// Panics if the range is empty.
         #[inline]
         pub fn $t(&mut self, range: impl RangeBounds<$t>) -> $t {
-            let panic_empty_range = || {
+            let (low, high) = $t::resolve_bounds(&range).unwrap_or_else(|| {
                 panic!(
                     "empty range: {:?}..{:?}",
                     range.start_bound(),
                     range.end_bound()
                 )
+            });
+            self.$inclusive(low, high)
+        }
+
+        /// Generates a random value in `low..=high`, where `low <= high`.
+        #[inline]
+        fn $inclusive(&mut self, low: $t, high: $t) -> $t {
+            if low == $t::MIN && high == $t::MAX {
+                self.$gen() as $t
+            } else {
+                let len = high.wrapping_sub(low).wrapping_add(1);
+                low.wrapping_add(self.$mod(len as $unsigned_t as _) as $t)
+            }
+        }
+    };
+}
+
+macro_rules! rng_nonzero {
+    ($name:ident, $t:tt, $nonzero:ident, $doc:tt) => {
+        #[doc = $doc]
+        ///
+        /// The value is uniformly distributed over all nonzero values of the type.
+        #[inline]
+        pub fn $name(&mut self) -> $nonzero {
+            $nonzero::new(self.$t(1..)).unwrap()
+        }
+    };
+}
+
+macro_rules! rng_parity {
+    ($name:ident, $t:tt, $unsigned_t:tt, $parity:expr, $doc:tt) => {
+        #[doc = $doc]
+        ///
+        /// The result is uniformly distributed over the values of that parity in the range.
+        ///
+        /// Panics if the range contains no such values.
+        #[inline]
+        pub fn $name(&mut self, range: impl RangeBounds<$t>) -> $t {
+            let panic_empty_range = || {
+                panic!(
+                    "no {} values in range: {:?}..{:?}",
+                    if $parity == 0 { "even" } else { "odd" },
+                    range.start_bound(),
+                    range.end_bound()
+                )
             };
 
             let low = match range.start_bound() {
-                Bound::Unbounded => core::$t::MIN,
+                Bound::Unbounded => $t::MIN,
                 Bound::Included(&x) => x,
                 Bound::Excluded(&x) => x.checked_add(1).unwrap_or_else(panic_empty_range),
             };
 
             let high = match range.end_bound() {
-                Bound::Unbounded => core::$t::MAX,
+                Bound::Unbounded => $t::MAX,
                 Bound::Included(&x) => x,
                 Bound::Excluded(&x) => x.checked_sub(1).unwrap_or_else(panic_empty_range),
             };
 
-            if low > high {
+            // Move each bound inwards to the nearest value with the right parity.
+            let first = if low & 1 == $parity {
+                low
+            } else {
+                low.checked_add(1).unwrap_or_else(panic_empty_range)
+            };
+            let last = if high & 1 == $parity {
+                high
+            } else {
+                high.checked_sub(1).unwrap_or_else(panic_empty_range)
+            };
+
+            if first > last {
                 panic_empty_range();
             }
 
-            if low == core::$t::MIN && high == core::$t::MAX {
-                self.$gen() as $t
+            // Pick one of the values `first, first + 2, ..., last`.
+            let count = last.wrapping_sub(first) as $unsigned_t / 2;
+            first.wrapping_add((self.$unsigned_t(..=count) * 2) as $t)
+        }
+    };
+}
+
+macro_rules! float_step {
+    ($t:tt, $next_up:ident, $next_down:ident) => {
+        /// Returns the smallest value greater than `x`.
+        fn $next_up(x: $t) -> $t {
+            if x.is_nan() || x == $t::INFINITY {
+                x
+            } else if x == 0.0 {
+                $t::from_bits(1)
+            } else if x > 0.0 {
+                $t::from_bits(x.to_bits() + 1)
             } else {
-                let len = high.wrapping_sub(low).wrapping_add(1);
-                low.wrapping_add(self.$mod(len as $unsigned_t as _) as $t)
+                $t::from_bits(x.to_bits() - 1)
             }
         }
+
+        /// Returns the largest value less than `x`.
+        fn $next_down(x: $t) -> $t {
+            -$next_up(-x)
+        }
     };
 }
 
-impl Rng {
-    /// Creates a new random number generator with the initial seed.
-    #[inline]
-    #[must_use = "this creates a new instance of `Rng`; if you want to initialize the thread-local generator, use `fastrand::seed()` instead"]
-    pub fn with_seed(seed: u64) -> Self {
-        Rng(seed)
-    }
-
-    /// Clones the generator by deterministically deriving a new generator based on the initial
-    /// seed.
-    ///
-    /// This function can be used to create a new generator that is a "spinoff" of the old
-    /// generator. The new generator will not produce the same sequence of values as the
-    /// old generator.
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// // Seed two generators equally, and clone both of them.
-    /// let mut base1 = fastrand::Rng::with_seed(0x4d595df4d0f33173);
-    /// base1.bool(); // Use the generator once.
-    ///
-    /// let mut base2 = fastrand::Rng::with_seed(0x4d595df4d0f33173);
-    /// base2.bool(); // Use the generator once.
-    ///
-    /// let mut rng1 = base1.fork();
-    /// let mut rng2 = base2.fork();
-    ///
-    /// println!("rng1 returns {}", rng1.u32(..));
-    /// println!("rng2 returns {}", rng2.u32(..));
-    /// ```
-    #[inline]
-    #[must_use = "this creates a new instance of `Rng`"]
-    pub fn fork(&mut self) -> Self {
-        Rng::with_seed(self.gen_u64())
-    }
+float_step!(f32, next_up_f32, next_down_f32);
+float_step!(f64, next_up_f64, next_down_f64);
 
-    /// Generates a random `char` in ranges a-z and A-Z.
-    #[inline]
-    pub fn alphabetic(&mut self) -> char {
-        const CHARS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
-        *self.choice(CHARS).unwrap() as char
-    }
+/// Types whose ranges can be resolved into inclusive bounds.
+///
+/// Both the panicking range methods and their fallible counterparts go through this, so they
+/// always agree on which ranges are valid.
+trait ResolveBounds: Sized {
+    /// Returns the inclusive bounds of `range`, or `None` if no value can be generated in it.
+    fn resolve_bounds<R: RangeBounds<Self>>(range: &R) -> Option<(Self, Self)>;
+}
 
-    /// Generates a random `char` in ranges a-z, A-Z and 0-9.
-    #[inline]
-    pub fn alphanumeric(&mut self) -> char {
-        const CHARS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
-        *self.choice(CHARS).unwrap() as char
-    }
+macro_rules! resolve_integer_bounds {
+    ($($t:tt),*) => {$(
+        impl ResolveBounds for $t {
+            #[inline]
+            fn resolve_bounds<R: RangeBounds<$t>>(range: &R) -> Option<($t, $t)> {
+                let low = match range.start_bound() {
+                    Bound::Unbounded => $t::MIN,
+                    Bound::Included(&x) => x,
+                    Bound::Excluded(&x) => x.checked_add(1)?,
+                };
 
-    /// Generates a random `bool`.
-    #[inline]
-    pub fn bool(&mut self) -> bool {
-        self.u8(..) % 2 == 0
-    }
+                let high = match range.end_bound() {
+                    Bound::Unbounded => $t::MAX,
+                    Bound::Included(&x) => x,
+                    Bound::Excluded(&x) => x.checked_sub(1)?,
+                };
 
-    /// Generates a random digit in the given `base`.
-    ///
-    /// Digits are represented by `char`s in ranges 0-9 and a-z.
-    ///
-    /// Panics if the base is zero or greater than 36.
-    #[inline]
-    pub fn digit(&mut self, base: u32) -> char {
-        if base == 0 {
-            panic!("base cannot be zero");
-        }
-        if base > 36 {
-            panic!("base cannot be larger than 36");
-        }
-        let num = self.u8(..base as u8);
-        if num < 10 {
-            (b'0' + num) as char
-        } else {
-            (b'a' + num - 10) as char
+                if low > high {
+                    return None;
+                }
+                Some((low, high))
+            }
         }
-    }
+    )*};
+}
 
-    /// Generates a random `f32` in range `0..1`.
+resolve_integer_bounds!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+macro_rules! resolve_float_bounds {
+    ($t:tt, $next_up:ident, $next_down:ident) => {
+        impl ResolveBounds for $t {
+            /// An unbounded side resolves to the most negative or most positive finite value.
+            /// NaN bounds and infinite included bounds are rejected.
+            #[inline]
+            fn resolve_bounds<R: RangeBounds<$t>>(range: &R) -> Option<($t, $t)> {
+                let low = match range.start_bound() {
+                    Bound::Unbounded => $t::MIN,
+                    Bound::Included(&x) => x,
+                    Bound::Excluded(&x) => $next_up(x),
+                };
+
+                let high = match range.end_bound() {
+                    Bound::Unbounded => $t::MAX,
+                    Bound::Included(&x) => x,
+                    Bound::Excluded(&x) => $next_down(x),
+                };
+
+                if low.is_nan() || high.is_nan() || low > high {
+                    return None;
+                }
+                if low.is_infinite() || high.is_infinite() {
+                    return None;
+                }
+                Some((low, high))
+            }
+        }
+    };
+}
+
+resolve_float_bounds!(f32, next_up_f32, next_down_f32);
+resolve_float_bounds!(f64, next_up_f64, next_down_f64);
+
+/// The first surrogate code point, which is not a Unicode scalar value.
+const SURROGATE_START: u32 = 0xd800;
+/// The number of surrogate code points.
+const SURROGATE_LEN: u32 = 0x800;
+
+impl ResolveBounds for char {
+    /// Excluded bounds step over the surrogate code points.
+    #[inline]
+    fn resolve_bounds<R: RangeBounds<char>>(range: &R) -> Option<(char, char)> {
+        let low = match range.start_bound() {
+            Bound::Unbounded => 0u8 as char,
+            Bound::Included(&x) => x,
+            Bound::Excluded(&x) => {
+                let scalar = if x as u32 == SURROGATE_START - 1 {
+                    SURROGATE_START + SURROGATE_LEN
+                } else {
+                    x as u32 + 1
+                };
+                char::try_from(scalar).ok()?
+            }
+        };
+
+        let high = match range.end_bound() {
+            Bound::Unbounded => core::char::MAX,
+            Bound::Included(&x) => x,
+            Bound::Excluded(&x) => {
+                let scalar = if x as u32 == SURROGATE_START + SURROGATE_LEN {
+                    SURROGATE_START - 1
+                } else {
+                    (x as u32).wrapping_sub(1)
+                };
+                char::try_from(scalar).ok()?
+            }
+        };
+
+        if low > high {
+            return None;
+        }
+        Some((low, high))
+    }
+}
+
+macro_rules! rng_float_range {
+    ($name:ident, $inclusive:ident, $t:tt, $gen:ident, $doc:tt) => {
+        #[doc = $doc]
+        ///
+        /// Both inclusive and exclusive bounds are respected exactly: the result never equals an
+        /// excluded bound, even when the range is only a few values wide. An unbounded side
+        /// defaults to the most negative or most positive finite value.
+        ///
+        /// Panics if the range is empty, or if a bound is NaN or an included bound is infinite.
+        pub fn $name(&mut self, range: impl RangeBounds<$t>) -> $t {
+            let (low, high) = $t::resolve_bounds(&range).unwrap_or_else(|| {
+                panic!(
+                    "empty or non-finite range: {:?}..{:?}",
+                    range.start_bound(),
+                    range.end_bound()
+                )
+            });
+            self.$inclusive(low, high)
+        }
+
+        /// Generates a random value in `low..=high`, where `low <= high` and both are finite.
+        #[inline]
+        fn $inclusive(&mut self, low: $t, high: $t) -> $t {
+            // Interpolate instead of computing `high - low`, which can overflow. Rounding can
+            // push the result just outside the bounds, so clamp it back in.
+            let t = self.$gen();
+            let x = low * (1.0 - t) + high * t;
+            if x < low {
+                low
+            } else if x > high {
+                high
+            } else {
+                x
+            }
+        }
+    };
+}
+
+macro_rules! rng_float_range_excluding {
+    ($name:ident, $t:tt, $inclusive:ident, $doc:tt) => {
+        #[doc = $doc]
+        ///
+        /// Values within a relative epsilon of `exclude`, that is within `EPSILON * exclude.abs()`
+        /// of it, are rejected and drawn again. When `exclude` is zero, only zero itself is
+        /// rejected. The chance of a redraw is about the width of that window divided by the
+        /// width of the range, so for any range that is not just a few values wide it is
+        /// negligible.
+        ///
+        /// Panics if the range is empty, if a bound is NaN or an included bound is infinite, or
+        /// if every value in the range is excluded.
+        pub fn $name(&mut self, range: impl RangeBounds<$t>, exclude: $t) -> $t {
+            let (low, high) = $t::resolve_bounds(&range).unwrap_or_else(|| {
+                panic!(
+                    "empty or non-finite range: {:?}..{:?}",
+                    range.start_bound(),
+                    range.end_bound()
+                )
+            });
+
+            // If `exclude` is NaN or infinite, the window is NaN at one end and rejects nothing.
+            let tolerance = $t::EPSILON * exclude.abs();
+            let (min, max) = (exclude - tolerance, exclude + tolerance);
+            if min <= low && high <= max {
+                panic!(
+                    "range only contains excluded values: {:?}..{:?}",
+                    range.start_bound(),
+                    range.end_bound()
+                );
+            }
+
+            loop {
+                let x = self.$inclusive(low, high);
+                if !(min <= x && x <= max) {
+                    return x;
+                }
+            }
+        }
+    };
+}
+
+impl Rng {
+    /// Creates a new random number generator with the initial seed.
+    ///
+    /// The generator uses the current default [`Algorithm`]; see
+    /// [`with_algorithm`](Rng::with_algorithm) to pin it. This is a `const fn`, so it can be
+    /// used to initialize constants and statics.
+    #[inline]
+    #[must_use = "this creates a new instance of `Rng`; if you want to initialize the thread-local generator, use `fastrand::seed()` instead"]
+    pub const fn with_seed(seed: u64) -> Self {
+        Rng(Wyrand(seed), Spare::NONE)
+    }
+
+    /// Creates a new random number generator with the initial seed and a pinned algorithm.
+    ///
+    /// This produces the same sequence as [`with_seed`](Rng::with_seed) as long as `algorithm`
+    /// is the current default, but keeps producing that sequence if the default changes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fastrand::{Algorithm, Rng};
+    ///
+    /// let mut rng = Rng::with_algorithm(42, Algorithm::Wyrand);
+    /// assert_eq!(rng.u64(..), Rng::with_seed(42).u64(..));
+    /// ```
+    #[inline]
+    #[must_use = "this creates a new instance of `Rng`"]
+    pub fn with_algorithm(seed: u64, algorithm: Algorithm) -> Self {
+        match algorithm {
+            Algorithm::Wyrand => Rng(Wyrand(seed), Spare::NONE),
+        }
+    }
+
+    /// Creates a new random number generator from a 128-bit seed.
+    ///
+    /// The seed is folded into the 64-bit state in the same way as
+    /// [`from_seed_bytes`](Rng::from_seed_bytes) folds its little-endian bytes, so
+    /// `with_seed_128(x)` and `from_seed_bytes(&x.to_le_bytes())` are equal.
+    #[inline]
+    #[must_use = "this creates a new instance of `Rng`"]
+    pub fn with_seed_128(seed: u128) -> Self {
+        Rng::from_seed_bytes(&seed.to_le_bytes())
+    }
+
+    /// Creates a new random number generator from a seed of any length.
+    ///
+    /// The bytes are absorbed eight at a time into the 64-bit state with Wyrand's mixing
+    /// function, together with their length, so that the result is deterministic and identical
+    /// on every platform. Since the state only has 64 bits, different seeds may still produce the
+    /// same generator, although that is unlikely.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fastrand::Rng;
+    ///
+    /// let mut a = Rng::from_seed_bytes(b"level-1");
+    /// let mut b = Rng::from_seed_bytes(b"level-1");
+    /// assert_eq!(a.u64(..), b.u64(..));
+    /// ```
+    #[must_use = "this creates a new instance of `Rng`"]
+    pub fn from_seed_bytes(bytes: &[u8]) -> Self {
+        let mut state = bytes.len() as u64;
+        for chunk in bytes.chunks(8) {
+            let mut word = [0; 8];
+            word[..chunk.len()].copy_from_slice(chunk);
+            state = wyrand_const(state ^ u64::from_le_bytes(word)).1;
+        }
+        Rng(Wyrand(state), Spare::NONE)
+    }
+
+    /// Creates a new random number generator seeded by a hasher from `hasher`.
+    ///
+    /// The seed is the output of a fresh hasher that has not been written to, so it depends only
+    /// on the state of `hasher`. Randomly keyed hashers such as
+    /// [`RandomState`](std::collections::hash_map::RandomState) therefore give a source of
+    /// entropy that only needs the standard library; see
+    /// [`from_random_state`](Rng::from_random_state).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    /// use fastrand::Rng;
+    ///
+    /// // A hasher with fixed keys always gives the same generator.
+    /// let fixed = BuildHasherDefault::<DefaultHasher>::default();
+    /// assert_eq!(Rng::from_build_hasher(&fixed), Rng::from_build_hasher(&fixed));
+    /// ```
+    #[inline]
+    #[must_use = "this creates a new instance of `Rng`"]
+    pub fn from_build_hasher<H: BuildHasher>(hasher: &H) -> Self {
+        Rng::with_seed(hasher.build_hasher().finish())
+    }
+
+    /// Creates a new random number generator seeded from a new
+    /// [`RandomState`](std::collections::hash_map::RandomState).
+    ///
+    /// Every `RandomState` is created with different random keys, which the standard library
+    /// seeds from the operating system once per thread. This gives an unpredictable seed without
+    /// the `getrandom` feature, and without relying on the time and thread ID.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    #[must_use = "this creates a new instance of `Rng`"]
+    pub fn from_random_state() -> Self {
+        Rng::from_build_hasher(&std::collections::hash_map::RandomState::new())
+    }
+
+    /// Initializes this generator with the given seed.
+    ///
+    /// This cannot be a `const fn` on the minimum supported Rust version; use
+    /// [`with_seed`](Rng::with_seed) to create a generator in a `const` context.
+    #[inline]
+    pub fn seed(&mut self, seed: u64) {
+        self.0 .0 = seed;
+        self.1 = Spare::NONE;
+    }
+
+    /// Gives back **current** seed that is being held by this generator.
+    #[inline]
+    pub const fn get_seed(&self) -> u64 {
+        self.0 .0
+    }
+
+    /// Returns the current state of this generator as little-endian bytes.
+    ///
+    /// The encoding is the same on every platform, so the bytes can be stored and later passed to
+    /// [`Rng::from_bytes`] to continue the same sequence. The value cached by
+    /// [`normal`](Rng::normal) is not included; see [`Rng`].
+    #[inline]
+    pub fn to_bytes(&self) -> [u8; 8] {
+        self.0 .0.to_le_bytes()
+    }
+
+    /// Restores a generator from the bytes returned by [`Rng::to_bytes`].
+    #[inline]
+    #[must_use = "this creates a new instance of `Rng`"]
+    pub fn from_bytes(bytes: [u8; 8]) -> Rng {
+        Rng(Wyrand(u64::from_le_bytes(bytes)), Spare::NONE)
+    }
+
+    /// Advances this generator by `steps` outputs without generating them.
+    ///
+    /// Wyrand's state is a counter that advances by a fixed constant on every step, so the
+    /// generator can jump ahead in `O(1)` by adding `steps` times that constant, wrapping on
+    /// overflow. Each step corresponds to one 64-bit output; note that methods such as
+    /// [`u128`](Rng::u128) or the range methods may consume more than one step per call.
+    ///
+    /// Jumping is useful for splitting one seeded sequence into non-overlapping blocks, for
+    /// example by having worker `i` call `jump(i * block_len)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut a = fastrand::Rng::with_seed(7);
+    /// let mut b = a.clone();
+    ///
+    /// for _ in 0..1000 {
+    ///     a.u64(..);
+    /// }
+    /// b.jump(1000);
+    /// assert_eq!(a, b);
+    /// ```
+    #[inline]
+    pub fn jump(&mut self, steps: u64) {
+        self.0 .0 = self.0 .0.wrapping_add(steps.wrapping_mul(WY_CONST_0));
+        self.1 = Spare::NONE;
+    }
+
+    /// Moves this generator back by `steps` outputs.
+    ///
+    /// This is the exact inverse of [`jump`](Rng::jump), and also runs in `O(1)` because of the
+    /// counter structure of Wyrand's state: `rng.jump(n)` followed by `rng.rewind(n)` restores
+    /// the original state.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut rng = fastrand::Rng::with_seed(7);
+    /// let a = rng.u64(..);
+    /// let b = rng.u64(..);
+    ///
+    /// rng.rewind(2);
+    /// assert_eq!(rng.u64(..), a);
+    /// assert_eq!(rng.u64(..), b);
+    /// ```
+    #[inline]
+    pub fn rewind(&mut self, steps: u64) {
+        self.0 .0 = self.0 .0.wrapping_sub(steps.wrapping_mul(WY_CONST_0));
+        self.1 = Spare::NONE;
+    }
+}
+
+impl<B: Backend> Rng<B> {
+    /// Creates a new random number generator that uses the given backend.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fastrand::{Pcg32, Rng};
+    ///
+    /// let mut rng = Rng::from_backend(Pcg32::new(42, 54));
+    /// let x = rng.f64();
+    /// assert!((0.0..1.0).contains(&x));
+    /// ```
+    #[inline]
+    #[must_use = "this creates a new instance of `Rng`"]
+    pub fn from_backend(backend: B) -> Self {
+        Rng(backend, Spare::NONE)
+    }
+
+    /// Returns a reference to the backend of this generator.
+    #[inline]
+    pub fn backend(&self) -> &B {
+        &self.0
+    }
+
+    /// Generates a random `u32` over its full range.
+    ///
+    /// This is the raw output of the generator, equivalent to `u32(..)`, and is intended as a
+    /// building block for custom distributions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut rng = fastrand::Rng::new();
+    ///
+    /// // A random sign, taken from the top bit.
+    /// let negative = rng.next_u32() >> 31 == 1;
+    /// # let _ = negative;
+    /// ```
+    #[inline]
+    pub fn next_u32(&mut self) -> u32 {
+        self.gen_u32()
+    }
+
+    /// Generates a random `u64` over its full range.
+    ///
+    /// This is the raw output of the generator, equivalent to `u64(..)`, and is intended as a
+    /// building block for custom distributions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut rng = fastrand::Rng::with_seed(7);
+    /// let mut state = 7;
+    ///
+    /// assert_eq!(rng.next_u64(), fastrand::wyrand(&mut state));
+    /// ```
+    #[inline]
+    pub fn next_u64(&mut self) -> u64 {
+        self.gen_u64()
+    }
+
+    /// Generates a random `u128` over its full range.
+    ///
+    /// The result is built from two consecutive `u64` outputs, the first providing the high
+    /// bits, and is equivalent to `u128(..)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut a = fastrand::Rng::with_seed(7);
+    /// let mut b = a.clone();
+    ///
+    /// let hi = u128::from(b.next_u64());
+    /// let lo = u128::from(b.next_u64());
+    /// assert_eq!(a.next_u128(), hi << 64 | lo);
+    /// ```
+    #[inline]
+    pub fn next_u128(&mut self) -> u128 {
+        self.gen_u128()
+    }
+
+    /// Clones the generator by deterministically deriving a new generator based on the initial
+    /// seed.
+    ///
+    /// This function can be used to create a new generator that is a "spinoff" of the old
+    /// generator. The new generator will not produce the same sequence of values as the
+    /// old generator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Seed two generators equally, and clone both of them.
+    /// let mut base1 = fastrand::Rng::with_seed(0x4d595df4d0f33173);
+    /// base1.bool(); // Use the generator once.
+    ///
+    /// let mut base2 = fastrand::Rng::with_seed(0x4d595df4d0f33173);
+    /// base2.bool(); // Use the generator once.
+    ///
+    /// let mut rng1 = base1.fork();
+    /// let mut rng2 = base2.fork();
+    ///
+    /// println!("rng1 returns {}", rng1.u32(..));
+    /// println!("rng2 returns {}", rng2.u32(..));
+    /// ```
+    #[inline]
+    #[must_use = "this creates a new instance of `Rng`"]
+    pub fn fork(&mut self) -> Self {
+        Rng(B::seed_from_u64(self.gen_u64()), Spare::NONE)
+    }
+
+    /// Creates a new generator for an independent stream, deterministically derived from this
+    /// one.
+    ///
+    /// Unlike [`fork`](Rng::fork), which seeds the child directly with one output of this
+    /// generator, `split` passes that output through an additional mixing function (the
+    /// SplitMix64 finalizer). This decorrelates the child streams even when the parents were
+    /// seeded with nearby values, at the cost of a few more arithmetic operations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut parent = fastrand::Rng::with_seed(1);
+    /// let mut workers: Vec<fastrand::Rng> = (0..4).map(|_| parent.split()).collect();
+    ///
+    /// for rng in &mut workers {
+    ///     println!("{}", rng.u32(..));
+    /// }
+    /// ```
+    #[inline]
+    #[must_use = "this creates a new instance of `Rng`"]
+    pub fn split(&mut self) -> Self {
+        let mut z = self.gen_u64();
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        Rng(B::seed_from_u64(z ^ (z >> 31)), Spare::NONE)
+    }
+
+    /// Creates `n` new generators, each derived from this one as with [`fork`](Rng::fork).
+    ///
+    /// Every child is seeded with a different output of this generator. In the unlikely event
+    /// that an output repeats, it is skipped, so no two children ever share a seed. Otherwise
+    /// the result is the same as calling `fork` `n` times.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut parent = fastrand::Rng::with_seed(1);
+    /// let workers = parent.fork_n(8);
+    ///
+    /// assert_eq!(workers.len(), 8);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[must_use = "this creates new instances of `Rng`"]
+    pub fn fork_n(&mut self, n: usize) -> Vec<Self> {
+        let mut seeds = BTreeSet::new();
+        let mut children = Vec::with_capacity(n);
+        while children.len() < n {
+            let seed = self.gen_u64();
+            if seeds.insert(seed) {
+                children.push(Rng(B::seed_from_u64(seed), Spare::NONE));
+            }
+        }
+        children
+    }
+
+    /// Generates a random `char` in ranges a-z and A-Z.
+    #[inline]
+    pub fn alphabetic(&mut self) -> char {
+        const CHARS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+        *self.choice(CHARS).unwrap() as char
+    }
+
+    /// Generates a random `char` in ranges a-z, A-Z and 0-9.
+    #[inline]
+    pub fn alphanumeric(&mut self) -> char {
+        const CHARS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+        *self.choice(CHARS).unwrap() as char
+    }
+
+    /// Randomly changes the case of an ASCII letter.
+    ///
+    /// ASCII letters are returned in uppercase or lowercase with equal probability, regardless
+    /// of their original case. Other characters are returned unchanged, without advancing the
+    /// generator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut rng = fastrand::Rng::new();
+    /// let keyword: String = "select".chars().map(|c| rng.random_case(c)).collect();
+    ///
+    /// assert!(keyword.eq_ignore_ascii_case("SELECT"));
+    /// ```
+    #[inline]
+    pub fn random_case(&mut self, c: char) -> char {
+        if !c.is_ascii_alphabetic() {
+            return c;
+        }
+        if self.bool() {
+            c.to_ascii_uppercase()
+        } else {
+            c.to_ascii_lowercase()
+        }
+    }
+
+    /// Chooses a random `char` from the characters of `set`.
+    ///
+    /// Each `char` of the string is equally likely, including multi-byte ones. Characters that
+    /// appear more than once in `set` are proportionally more likely to be chosen.
+    ///
+    /// Panics if `set` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut rng = fastrand::Rng::new();
+    /// let base = rng.char_from("ACGT");
+    ///
+    /// assert!("ACGT".contains(base));
+    /// ```
+    #[inline]
+    pub fn char_from(&mut self, set: &str) -> char {
+        let count = set.chars().count();
+        if count == 0 {
+            panic!("empty character set");
+        }
+        set.chars().nth(self.usize(..count)).unwrap()
+    }
+
+    /// Generates a random printable ASCII `char` in range `' '..='~'`.
+    #[inline]
+    pub fn ascii_printable(&mut self) -> char {
+        self.u8(b' '..=b'~') as char
+    }
+
+    /// Generates a random string of `len` printable ASCII characters.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut rng = fastrand::Rng::new();
+    /// let s = rng.ascii_printable_string(20);
+    ///
+    /// assert_eq!(s.len(), 20);
+    /// assert!(s.chars().all(|c| c.is_ascii_graphic() || c == ' '));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn ascii_printable_string(&mut self, len: usize) -> String {
+        let mut s = String::with_capacity(len);
+        s.extend((0..len).map(|_| self.ascii_printable()));
+        s
+    }
+
+    /// Generates a random `bool`.
+    #[inline]
+    pub fn bool(&mut self) -> bool {
+        self.u8(..) % 2 == 0
+    }
+
+    /// Generates a random `bool` that is `true` with probability `p`.
+    ///
+    /// If `p <= 0.0` or `p` is NaN the result is always `false`, and if `p >= 1.0` it is always
+    /// `true`. The resolution of `p` is limited by the 52 random mantissa bits of
+    /// [`f64()`](Rng::f64), so `p` is effectively rounded up to a multiple of `2^-52`. In
+    /// particular, any `p` in `0.0 < p < 2^-52` gives `true` with probability `2^-52`.
+    #[inline]
+    pub fn bool_with_probability(&mut self, p: f64) -> bool {
+        self.f64() < p
+    }
+
+    /// Generates a random `bool` that is `true` with probability `numerator / denominator`.
+    ///
+    /// Unlike [`bool_with_probability`](Rng::bool_with_probability), this uses only integer
+    /// arithmetic and is exact. If `numerator >= denominator` the result is always `true`.
+    ///
+    /// Panics if `denominator` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut rng = fastrand::Rng::new();
+    ///
+    /// // Succeed 3 times out of 7.
+    /// let hit = rng.ratio(3, 7);
+    /// # let _ = hit;
+    /// ```
+    #[inline]
+    pub fn ratio(&mut self, numerator: u32, denominator: u32) -> bool {
+        if denominator == 0 {
+            panic!("denominator cannot be zero");
+        }
+        numerator >= denominator || self.u32(..denominator) < numerator
+    }
+
+    /// Generates `-1.0` or `1.0` with equal probability.
+    #[inline]
+    pub fn sign_f64(&mut self) -> f64 {
+        if self.bool() {
+            1.0
+        } else {
+            -1.0
+        }
+    }
+
+    /// Generates `-1` or `1` with equal probability.
+    #[inline]
+    pub fn sign_i32(&mut self) -> i32 {
+        if self.bool() {
+            1
+        } else {
+            -1
+        }
+    }
+
+    /// Generates a random color as red, green and blue channels.
+    #[inline]
+    pub fn rgb(&mut self) -> (u8, u8, u8) {
+        let [r, g, b, _] = self.gen_u32().to_le_bytes();
+        (r, g, b)
+    }
+
+    /// Generates a random color as red, green, blue and alpha channels.
+    #[inline]
+    pub fn rgba(&mut self) -> (u8, u8, u8, u8) {
+        let [r, g, b, a] = self.gen_u32().to_le_bytes();
+        (r, g, b, a)
+    }
+
+    /// Generates a random color as hue, saturation and value.
+    ///
+    /// The hue is in degrees in range `0..360`, and saturation and value are in range `0..1`.
+    #[inline]
+    pub fn hsv(&mut self) -> (f64, f64, f64) {
+        (self.f64_range(0.0..360.0), self.f64(), self.f64())
+    }
+
+    /// Generates a random digit in the given `base`.
+    ///
+    /// Digits are represented by `char`s in ranges 0-9 and a-z.
+    ///
+    /// Panics if the base is zero or greater than 36.
+    #[inline]
+    pub fn digit(&mut self, base: u32) -> char {
+        if base == 0 {
+            panic!("base cannot be zero");
+        }
+        if base > 36 {
+            panic!("base cannot be larger than 36");
+        }
+        let num = self.u8(..base as u8);
+        if num < 10 {
+            (b'0' + num) as char
+        } else {
+            (b'a' + num - 10) as char
+        }
+    }
+
+    /// Generates a random string of `n` digits in the given `base`.
+    ///
+    /// Digits are represented by `char`s in ranges 0-9 and a-z, as with [`digit`](Rng::digit).
+    ///
+    /// Panics if the base is zero or greater than 36, even if `n` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut rng = fastrand::Rng::new();
+    /// let s = rng.digits(8, 12);
+    ///
+    /// assert_eq!(s.len(), 12);
+    /// assert!(s.chars().all(|c| c.is_digit(8)));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn digits(&mut self, base: u32, n: usize) -> String {
+        if base == 0 {
+            panic!("base cannot be zero");
+        }
+        if base > 36 {
+            panic!("base cannot be larger than 36");
+        }
+        let mut s = String::with_capacity(n);
+        s.extend((0..n).map(|_| self.digit(base)));
+        s
+    }
+
+    /// Generates a random string of `len` characters from the given character set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fastrand::Charset;
+    ///
+    /// let mut rng = fastrand::Rng::new();
+    /// let name = rng.string(8, Charset::Lowercase);
+    ///
+    /// assert_eq!(name.len(), 8);
+    /// assert!(name.chars().all(|c| c.is_ascii_lowercase()));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn string(&mut self, len: usize, charset: Charset) -> String {
+        let chars = charset.chars();
+        let mut s = String::with_capacity(len);
+        for _ in 0..len {
+            s.push(chars[self.usize(..chars.len())] as char);
+        }
+        s
+    }
+
+    /// Generates a random string of `len` lowercase hexadecimal digits.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut rng = fastrand::Rng::new();
+    /// let token = rng.hex_string(32);
+    ///
+    /// assert_eq!(token.len(), 32);
+    /// assert!(token.chars().all(|c| c.is_ascii_hexdigit()));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn hex_string(&mut self, len: usize) -> String {
+        const HEX: &[u8] = b"0123456789abcdef";
+
+        let mut s = String::with_capacity(len);
+        while s.len() < len {
+            // Each block of WyRand output provides 16 digits.
+            let mut n = self.gen_u64();
+            for _ in 0..(len - s.len()).min(16) {
+                s.push(HEX[(n & 0xf) as usize] as char);
+                n >>= 4;
+            }
+        }
+        s
+    }
+
+    /// Generates a random `f32` in range `0..1`.
+    ///
+    /// The result is always finite and never negative zero, NaN, or subnormal: it is a
+    /// multiple of `2^-23`, so it is either exactly `0.0` or at least `2^-23`.
     pub fn f32(&mut self) -> f32 {
         let b = 32;
-        let f = core::f32::MANTISSA_DIGITS - 1;
+        let f = f32::MANTISSA_DIGITS - 1;
         f32::from_bits((1 << (b - 2)) - (1 << f) + (self.u32(..) >> (b - f))) - 1.0
     }
 
-    /// Generates a random `f64` in range `0..1`.
-    pub fn f64(&mut self) -> f64 {
-        let b = 64;
-        let f = core::f64::MANTISSA_DIGITS - 1;
-        f64::from_bits((1 << (b - 2)) - (1 << f) + (self.u64(..) >> (b - f))) - 1.0
+    /// Generates a random `f64` in range `0..1`.
+    ///
+    /// The result is always finite and never negative zero, NaN, or subnormal: it is a
+    /// multiple of `2^-52`, so it is either exactly `0.0` or at least `2^-52`.
+    pub fn f64(&mut self) -> f64 {
+        let b = 64;
+        let f = f64::MANTISSA_DIGITS - 1;
+        f64::from_bits((1 << (b - 2)) - (1 << f) + (self.u64(..) >> (b - f))) - 1.0
+    }
+
+    /// Generates a random `f64` in range `0..1` with 53 bits of precision.
+    ///
+    /// The result is a uniformly random 53-bit integer divided by `2^53`, so every
+    /// multiple of `2^-53` in `0..1` is equally likely, and the smallest nonzero value
+    /// is `2^-53`.
+    ///
+    /// [`f64()`](Rng::f64) instead fills the 52-bit mantissa of a float in `1..2` and subtracts
+    /// one, so its results are multiples of `2^-52` and its smallest nonzero value is
+    /// `2^-52`. Both methods are uniform; this one has twice the resolution, which
+    /// matters mostly near zero, at the cost of an integer to float conversion.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut rng = fastrand::Rng::new();
+    /// let x = rng.f64_precise();
+    ///
+    /// assert!((0.0..1.0).contains(&x));
+    /// ```
+    #[inline]
+    pub fn f64_precise(&mut self) -> f64 {
+        const SCALE: f64 = 1.0 / (1u64 << 53) as f64;
+        (self.gen_u64() >> 11) as f64 * SCALE
+    }
+
+    /// Generates a random `f64` in the open interval `(0, 1)`.
+    ///
+    /// The result is `(k + 0.5) / 2^52` for a uniformly random integer `k` in `0..2^52`, so it is
+    /// never exactly `0.0` or `1.0`: the smallest value is `2^-53` and the largest is
+    /// `1 - 2^-53`. This makes it safe to pass the result to functions such as `ln` that are
+    /// not finite at zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut rng = fastrand::Rng::new();
+    /// let x = rng.f64_open();
+    ///
+    /// assert!(x > 0.0 && x < 1.0);
+    /// ```
+    #[inline]
+    pub fn f64_open(&mut self) -> f64 {
+        const SCALE: f64 = 1.0 / (1u64 << 52) as f64;
+        ((self.gen_u64() >> 12) as f64 + 0.5) * SCALE
+    }
+
+    /// Generates a random `f64` in the closed interval `[0, 1]`.
+    ///
+    /// The result is `k / (2^53 - 1)` for a uniformly random integer `k` in `0..2^53`, so both
+    /// `0.0` and `1.0` can be returned, each with probability `2^-53`.
+    #[inline]
+    pub fn f64_closed(&mut self) -> f64 {
+        const MAX: f64 = ((1u64 << 53) - 1) as f64;
+        // Dividing rather than multiplying by the reciprocal makes `MAX / MAX` exactly one.
+        (self.gen_u64() >> 11) as f64 / MAX
+    }
+
+    rng_float_range!(
+        f32_range,
+        f32_inclusive,
+        f32,
+        f32,
+        "Generates a random `f32` in the given range."
+    );
+
+    rng_float_range!(
+        f64_range,
+        f64_inclusive,
+        f64,
+        f64,
+        "Generates a random `f64` in the given range."
+    );
+
+    rng_float_range_excluding!(
+        f32_range_excluding,
+        f32,
+        f32_inclusive,
+        "Generates a random `f32` in the given range, other than `exclude`."
+    );
+
+    rng_float_range_excluding!(
+        f64_range_excluding,
+        f64,
+        f64_inclusive,
+        "Generates a random `f64` in the given range, other than `exclude`."
+    );
+
+    /// Fills `out` with a random point inside the axis-aligned box bounded by `mins` and `maxs`.
+    ///
+    /// Each coordinate `out[i]` is generated with [`f64_range(mins[i]..maxs[i])`](Rng::f64_range),
+    /// or set to `mins[i]` if `mins[i] == maxs[i]`.
+    ///
+    /// Panics if the slices have different lengths, or if any bound is not finite or
+    /// `mins[i] > maxs[i]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut rng = fastrand::Rng::new();
+    /// let mut point = [0.0; 3];
+    /// rng.point_in_box(&[0.0, -1.0, 10.0], &[1.0, 1.0, 20.0], &mut point);
+    ///
+    /// assert!(point[0] >= 0.0 && point[0] < 1.0);
+    /// assert!(point[1] >= -1.0 && point[1] < 1.0);
+    /// assert!(point[2] >= 10.0 && point[2] < 20.0);
+    /// ```
+    pub fn point_in_box(&mut self, mins: &[f64], maxs: &[f64], out: &mut [f64]) {
+        if mins.len() != maxs.len() || mins.len() != out.len() {
+            panic!("box bounds and output must have the same number of dimensions");
+        }
+
+        for ((&low, &high), slot) in mins.iter().zip(maxs).zip(out.iter_mut()) {
+            // `f64_range` accepts an infinite excluded upper bound, so check this up front.
+            if !low.is_finite() || !high.is_finite() {
+                panic!("box bounds must be finite: {:?}..{:?}", low, high);
+            }
+            *slot = if low == high {
+                low
+            } else {
+                self.f64_range(low..high)
+            };
+        }
+    }
+
+    /// Generates a random `(latitude, longitude)` pair uniformly distributed over a sphere.
+    ///
+    /// Both values are in degrees: the latitude is in `-90.0..90.0` and the longitude is in
+    /// `-180.0..180.0`. The latitude is computed as `asin(2u - 1)` rather than drawn uniformly,
+    /// so that points don't cluster around the poles.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn lat_lon(&mut self) -> (f64, f64) {
+        let lat = (2.0 * self.f64() - 1.0).asin().to_degrees();
+        let lon = 360.0 * self.f64() - 180.0;
+        (lat, lon)
+    }
+
+    /// Generates a random angle in radians, in range `0..2π`.
+    #[inline]
+    pub fn angle(&mut self) -> f64 {
+        core::f64::consts::PI * 2.0 * self.f64()
+    }
+
+    /// Generates a random point uniformly distributed on the unit circle.
+    ///
+    /// A point is first drawn uniformly from the unit disk by rejection sampling on the enclosing
+    /// square, and then mapped onto the circle with the doubled angle, which avoids both
+    /// trigonometric functions and square roots.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut rng = fastrand::Rng::new();
+    /// let (x, y) = rng.unit_vector_2d();
+    ///
+    /// assert!((x * x + y * y - 1.0).abs() < 1e-9);
+    /// ```
+    pub fn unit_vector_2d(&mut self) -> (f64, f64) {
+        loop {
+            let x = 2.0 * self.f64() - 1.0;
+            let y = 2.0 * self.f64() - 1.0;
+            let r2 = x * x + y * y;
+            // Reject points outside the disk and too close to the center to scale accurately.
+            if r2 <= 1.0 && r2 > 1e-12 {
+                return ((x * x - y * y) / r2, 2.0 * x * y / r2);
+            }
+        }
+    }
+
+    /// Generates a random point uniformly distributed on the surface of the unit sphere.
+    ///
+    /// This uses Marsaglia's method: a point `(u, v)` is drawn uniformly from the unit disk by
+    /// rejection sampling, and with `s = u² + v²` the result is
+    /// `(2u·√(1 - s), 2v·√(1 - s), 1 - 2s)`. Since `1 - 2s` is uniform in `-1..1`, the points
+    /// are not biased towards the poles.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut rng = fastrand::Rng::new();
+    /// let (x, y, z) = rng.unit_vector_3d();
+    ///
+    /// assert!((x * x + y * y + z * z - 1.0).abs() < 1e-9);
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn unit_vector_3d(&mut self) -> (f64, f64, f64) {
+        loop {
+            let u = self.f64_range(-1.0..1.0);
+            let v = self.f64_range(-1.0..1.0);
+            let s = u * u + v * v;
+            if s < 1.0 {
+                let scale = 2.0 * (1.0 - s).sqrt();
+                return (u * scale, v * scale, 1.0 - 2.0 * s);
+            }
+        }
+    }
+
+    /// Generates a random `f64` from the exponential distribution with the given rate `lambda`.
+    ///
+    /// The mean of the distribution is `1 / lambda`.
+    ///
+    /// Panics if `lambda` is not positive.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn exp(&mut self, lambda: f64) -> f64 {
+        if lambda <= 0.0 || lambda.is_nan() {
+            panic!("rate must be positive: {:?}", lambda);
+        }
+
+        // `1 - f64()` is in `(0, 1]`, so the logarithm is never infinite.
+        -(1.0 - self.f64()).ln() / lambda
+    }
+
+    /// Generates a random `u64` from the geometric distribution with success probability `p`.
+    ///
+    /// The result is the number of failed Bernoulli trials before the first success, so the
+    /// mean of the distribution is `(1 - p) / p`. If `p >= 1.0` the result is always zero, and
+    /// results that do not fit in a `u64` saturate to `u64::MAX`.
+    ///
+    /// Panics if `p` is not positive.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut rng = fastrand::Rng::new();
+    /// let retries = rng.geometric(0.25);
+    /// # let _ = retries;
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn geometric(&mut self, p: f64) -> u64 {
+        if p <= 0.0 || p.is_nan() {
+            panic!("probability must be positive: {:?}", p);
+        }
+        if p >= 1.0 {
+            return 0;
+        }
+
+        // `ln_1p` keeps `ln(1 - p)` accurate for tiny `p`, where `1 - p` would round to one.
+        let u = 1.0 - self.f64();
+        (u.ln() / (-p).ln_1p()).floor() as u64
+    }
+
+    /// Generates a random `f64` from the normal distribution with the given mean and standard
+    /// deviation.
+    ///
+    /// Values are generated with the Box-Muller transform, which produces them in pairs. The
+    /// second value of each pair is cached in the generator and used by the next call, so only
+    /// every other call draws new random numbers.
+    ///
+    /// Panics if `std_dev` is negative or NaN.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn normal(&mut self, mean: f64, std_dev: f64) -> f64 {
+        if std_dev < 0.0 || std_dev.is_nan() {
+            panic!("standard deviation must be non-negative: {:?}", std_dev);
+        }
+
+        // Each round of Box-Muller produces two independent values; the second one is cached.
+        let z = match self.1.take() {
+            Some(z) => z,
+            None => {
+                // `1 - f64()` is in `(0, 1]`, which keeps the logarithm finite.
+                let u1 = 1.0 - self.f64();
+                let u2 = self.f64();
+                let radius = (-2.0 * u1.ln()).sqrt();
+                let theta = 2.0 * core::f64::consts::PI * u2;
+                self.1.set(radius * theta.sin());
+                radius * theta.cos()
+            }
+        };
+        mean + std_dev * z
+    }
+
+    /// Randomly spreads `base` by up to `jitter_fraction` of its length in either direction.
+    ///
+    /// The result is `base` scaled by a uniform factor in `1 - jitter_fraction..=1 +
+    /// jitter_fraction`, saturating at the largest representable [`Duration`]. This is useful
+    /// for spreading out cache expiry times so that entries don't all expire at once.
+    ///
+    /// Panics if `jitter_fraction` is not in `0.0..=1.0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// let mut rng = fastrand::Rng::new();
+    /// let ttl = rng.jitter_duration(Duration::from_secs(60), 0.1);
+    ///
+    /// assert!(ttl >= Duration::from_secs(54) && ttl <= Duration::from_secs(66));
+    /// ```
+    pub fn jitter_duration(&mut self, base: Duration, jitter_fraction: f64) -> Duration {
+        if !(0.0..=1.0).contains(&jitter_fraction) {
+            panic!(
+                "jitter fraction must be in 0.0..=1.0: {:?}",
+                jitter_fraction
+            );
+        }
+
+        let factor = 1.0 - jitter_fraction + 2.0 * jitter_fraction * self.f64_closed();
+        duration_from_nanos_f64(base.as_nanos() as f64 * factor)
+    }
+
+    /// Generates a retry delay for exponential backoff with "full jitter".
+    ///
+    /// The delay is uniformly distributed in `0..=min(cap, base * 2^attempt)`, with nanosecond
+    /// resolution. Randomizing the whole delay, rather than adding a little noise to it, keeps
+    /// clients that failed at the same time from retrying in lockstep. If `base * 2^attempt`
+    /// overflows, including for very large `attempt`s, the upper bound is `cap`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// let mut rng = fastrand::Rng::new();
+    /// let base = Duration::from_millis(100);
+    /// let cap = Duration::from_secs(10);
+    ///
+    /// for attempt in 0..5 {
+    ///     let delay = rng.backoff_jitter(base, cap, attempt);
+    ///     assert!(delay <= base * 2u32.pow(attempt));
+    /// }
+    /// ```
+    pub fn backoff_jitter(&mut self, base: Duration, cap: Duration, attempt: u32) -> Duration {
+        let base = base.as_nanos();
+        let cap = cap.as_nanos();
+        let max = match 1u128
+            .checked_shl(attempt)
+            .and_then(|factor| base.checked_mul(factor))
+        {
+            Some(nanos) => nanos.min(cap),
+            None if base == 0 => 0,
+            None => cap,
+        };
+
+        // `max` is at most `cap`, so the whole seconds fit in a `u64`.
+        let nanos = self.u128(..=max);
+        Duration::new(
+            (nanos / 1_000_000_000) as u64,
+            (nanos % 1_000_000_000) as u32,
+        )
+    }
+
+    /// Returns an endless iterator that generates each item by calling `f` with this generator.
+    ///
+    /// The iterator borrows the generator mutably for its lifetime, and never returns `None`,
+    /// so it should be bounded with an adapter such as [`take`](Iterator::take).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut rng = fastrand::Rng::new();
+    /// let dice: Vec<u8> = rng.iter_with(|r| r.u8(1..=6)).take(10).collect();
+    ///
+    /// assert_eq!(dice.len(), 10);
+    /// ```
+    #[inline]
+    pub fn iter_with<'a, T, F>(&'a mut self, mut f: F) -> impl Iterator<Item = T> + 'a
+    where
+        F: FnMut(&mut Rng<B>) -> T + 'a,
+    {
+        core::iter::repeat_with(move || f(self))
+    }
+
+    /// Returns an endless iterator of random `bool`s.
+    ///
+    /// See [`iter_with`](Rng::iter_with) for details.
+    #[inline]
+    pub fn iter_bool(&mut self) -> impl Iterator<Item = bool> + '_ {
+        self.iter_with(Rng::bool)
+    }
+
+    /// Returns an endless iterator of random `u32`s.
+    ///
+    /// See [`iter_with`](Rng::iter_with) for details.
+    #[inline]
+    pub fn iter_u32(&mut self) -> impl Iterator<Item = u32> + '_ {
+        self.iter_with(Rng::gen_u32)
+    }
+
+    /// Returns an endless iterator of random `u64`s.
+    ///
+    /// See [`iter_with`](Rng::iter_with) for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut rng = fastrand::Rng::new();
+    /// let ids: Vec<u64> = rng.iter_u64().take(10).collect();
+    ///
+    /// assert_eq!(ids.len(), 10);
+    /// ```
+    #[inline]
+    pub fn iter_u64(&mut self) -> impl Iterator<Item = u64> + '_ {
+        self.iter_with(Rng::gen_u64)
+    }
+
+    /// Returns an endless iterator of random `f64`s in range `0..1`.
+    ///
+    /// See [`iter_with`](Rng::iter_with) for details.
+    #[inline]
+    pub fn iter_f64(&mut self) -> impl Iterator<Item = f64> + '_ {
+        self.iter_with(Rng::f64)
+    }
+
+    /// Collects `amount` values at random from the iterable into a vector.
+    ///
+    /// The length of the returned vector equals `amount` unless the iterable
+    /// contains insufficient elements, in which case it equals the number of
+    /// elements available.
+    ///
+    /// The iterable is consumed in a single pass using reservoir sampling, so its length does
+    /// not need to be known in advance and at most `amount` items are held in memory. Every
+    /// subset of `amount` items is equally likely to be chosen, but the order of the items in
+    /// the returned vector is not random.
+    ///
+    /// Complexity is `O(n)` where `n` is the length of the iterable.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut rng = fastrand::Rng::new();
+    /// let lines = "a\nb\nc\nd\ne".lines();
+    /// let sample = rng.choose_multiple(lines, 2);
+    ///
+    /// assert_eq!(sample.len(), 2);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn choose_multiple<I: IntoIterator>(&mut self, source: I, amount: usize) -> Vec<I::Item> {
+        // Adapted from: https://docs.rs/rand/latest/rand/seq/trait.IteratorRandom.html#method.choose_multiple
+        let mut reservoir = Vec::with_capacity(amount);
+        let mut iter = source.into_iter();
+
+        reservoir.extend(iter.by_ref().take(amount));
+
+        // Continue unless the iterator was exhausted
+        //
+        // note: this prevents iterators that "restart" from causing problems.
+        // If the iterator stops once, then so do we.
+        if reservoir.len() == amount {
+            for (i, elem) in iter.enumerate() {
+                let end = i + 1 + amount;
+                let k = self.usize(0..end);
+                if let Some(slot) = reservoir.get_mut(k) {
+                    *slot = elem;
+                }
+            }
+        } else {
+            // If less than one third of the `Vec` was used, reallocate
+            // so that the unused space is not wasted. There is a corner
+            // case where `amount` was much less than `self.len()`.
+            if reservoir.capacity() > 3 * reservoir.len() {
+                reservoir.shrink_to_fit();
+            }
+        }
+        reservoir
+    }
+
+    /// Lazily chooses up to `amount` distinct items from a slice, in random order.
+    ///
+    /// This performs a partial Fisher-Yates shuffle of the indices one step per item, so it
+    /// yields the same items in the same order as [`partial_shuffle`](Rng::partial_shuffle)
+    /// would leave at the front of the slice. Unlike that method, the slice is not modified, and
+    /// unlike [`choose_multiple`](Rng::choose_multiple), no vector of results is built. Only
+    /// the displaced indices are remembered, so memory grows with the number of items taken
+    /// rather than with the length of the slice.
+    ///
+    /// If the slice has fewer than `amount` items, all of them are yielded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut rng = fastrand::Rng::new();
+    /// let words = ["apple", "banana", "cherry", "date"];
+    ///
+    /// for word in rng.choose_multiple_iter(&words, 2) {
+    ///     println!("{}", word);
+    /// }
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn choose_multiple_iter<'a, T>(
+        &'a mut self,
+        items: &'a [T],
+        amount: usize,
+    ) -> impl Iterator<Item = &'a T> + 'a {
+        let len = items.len();
+        let amount = amount.min(len);
+        // Maps a position to the index it holds, for positions that have been swapped.
+        let mut moved = BTreeMap::new();
+        let mut i = 0;
+
+        core::iter::from_fn(move || {
+            if i == amount {
+                return None;
+            }
+            let j = self.usize(i..len);
+            let picked = moved.remove(&j).unwrap_or(j);
+            if j != i {
+                // Position `i` is never visited again, so only `j` needs to remember its value.
+                let displaced = moved.remove(&i).unwrap_or(i);
+                moved.insert(j, displaced);
+            }
+            i += 1;
+            Some(&items[picked])
+        })
+    }
+
+    /// Collects `amount` items at random from a stream of weighted items, without replacement.
+    ///
+    /// Each item is chosen with probability proportional to its weight, relative to the items
+    /// that have not been chosen yet. The stream is consumed in a single pass using the A-Res
+    /// algorithm of Efraimidis and Spirakis, which gives every item the key `u^(1/w)` for a
+    /// uniform `u` and keeps the `amount` items with the largest keys, so the length of the
+    /// stream does not need to be known in advance. The order of the returned items is
+    /// unspecified.
+    ///
+    /// Items with a weight of zero are never chosen, so fewer than `amount` items are returned
+    /// if the stream does not contain enough items with positive weights.
+    ///
+    /// Panics if a weight is negative or not finite.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut rng = fastrand::Rng::new();
+    /// let servers = vec![("eu", 5.0), ("us", 3.0), ("asia", 2.0), ("down", 0.0)];
+    ///
+    /// let picked = rng.sample_iter_weighted(servers, 2);
+    /// assert_eq!(picked.len(), 2);
+    /// assert!(!picked.contains(&"down"));
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn sample_iter_weighted<T>(
+        &mut self,
+        iter: impl IntoIterator<Item = (T, f64)>,
+        amount: usize,
+    ) -> Vec<T> {
+        if amount == 0 {
+            return Vec::new();
+        }
+
+        let mut heap = BinaryHeap::with_capacity(amount);
+        for (item, weight) in iter {
+            if weight < 0.0 || !weight.is_finite() {
+                panic!("invalid weight: {:?}", weight);
+            }
+            if weight == 0.0 {
+                continue;
+            }
+
+            // `ln(u) / w` orders items like `u^(1/w)`, without underflowing for small weights.
+            let key = self.f64_open().ln() / weight;
+            if heap.len() < amount {
+                heap.push(KeyedItem { key, item });
+            } else if let Some(mut min) = heap.peek_mut() {
+                if key > min.key {
+                    *min = KeyedItem { key, item };
+                }
+            }
+        }
+
+        heap.into_iter().map(|keyed| keyed.item).collect()
+    }
+
+    /// Collects `amount` items chosen at random from `items`, with replacement.
+    ///
+    /// Every item is chosen independently, so the result may contain duplicates, and `amount`
+    /// may exceed the number of items. This is the sampling used, for example, by bootstrap
+    /// resampling. For distinct items, see [`choose_multiple`](Rng::choose_multiple).
+    ///
+    /// Panics if `items` is empty and `amount` is not zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut rng = fastrand::Rng::new();
+    /// let data = [2.0, 3.5, 4.0];
+    /// let resample = rng.choose_multiple_with_replacement(&data, 10);
+    ///
+    /// assert_eq!(resample.len(), 10);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn choose_multiple_with_replacement<T: Clone>(
+        &mut self,
+        items: &[T],
+        amount: usize,
+    ) -> Vec<T> {
+        if items.is_empty() && amount > 0 {
+            panic!("cannot choose from an empty slice");
+        }
+        (0..amount)
+            .map(|_| items[self.usize(..items.len())].clone())
+            .collect()
+    }
+
+    /// Chooses a random index into `weights`, with probability proportional to each weight.
+    ///
+    /// Returns `None` if `weights` is empty, if any weight is negative or not finite, or if the
+    /// weights sum to zero. Complexity is `O(n)` where `n` is the length of `weights`.
+    ///
+    /// This is the selection used by [`weighted_choice`](Rng::weighted_choice), and is useful
+    /// when the weights and the items they describe are stored separately. To sample many
+    /// times from the same weights, [`WeightedIndex`] is faster.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut rng = fastrand::Rng::new();
+    /// let i = rng.weighted_index(&[0.0, 1.0, 0.0]);
+    ///
+    /// assert_eq!(i, Some(1));
+    /// ```
+    #[inline]
+    pub fn weighted_index(&mut self, weights: &[f64]) -> Option<usize> {
+        self.gen_weighted_index(weights)
+    }
+
+    /// Chooses a random index into `weights`, with probability proportional to each weight.
+    ///
+    /// This is the same as [`weighted_index`](Rng::weighted_index), but takes `f32` weights
+    /// so that large weight tables don't need to be converted to `f64`. The weights are
+    /// accumulated in `f64` for precision.
+    #[inline]
+    pub fn weighted_index_f32(&mut self, weights: &[f32]) -> Option<usize> {
+        self.gen_weighted_index(weights)
     }
 
-    /// Collects `amount` values at random from the iterable into a vector.
+    /// Chooses a random index into `weights`, with probability proportional to each weight.
     ///
-    /// The length of the returned vector equals `amount` unless the iterable
-    /// contains insufficient elements, in which case it equals the number of
-    /// elements available.
+    /// This is the same as [`weighted_index`](Rng::weighted_index).
+    #[inline]
+    #[deprecated(note = "use weighted_index")]
+    pub fn choose_weighted_index(&mut self, weights: &[f64]) -> Option<usize> {
+        self.weighted_index(weights)
+    }
+
+    /// Chooses a random index into `weights`, with probability proportional to each weight.
     ///
-    /// Complexity is `O(n)` where `n` is the length of the iterable.
-    #[cfg(feature = "alloc")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
-    pub fn choose_multiple<I: IntoIterator>(&mut self, source: I, amount: usize) -> Vec<I::Item> {
-        // Adapted from: https://docs.rs/rand/latest/rand/seq/trait.IteratorRandom.html#method.choose_multiple
-        let mut reservoir = Vec::with_capacity(amount);
-        let mut iter = source.into_iter();
+    /// This is the same as [`weighted_index_f32`](Rng::weighted_index_f32).
+    #[inline]
+    #[deprecated(note = "use weighted_index_f32")]
+    pub fn choose_weighted_index_f32(&mut self, weights: &[f32]) -> Option<usize> {
+        self.weighted_index_f32(weights)
+    }
 
-        reservoir.extend(iter.by_ref().take(amount));
+    /// Chooses an item at random, with probability proportional to its weight.
+    ///
+    /// Returns `None` if `items` is empty, if `items` and `weights` have different lengths, if
+    /// any weight is negative or not finite, or if the weights sum to zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut rng = fastrand::Rng::new();
+    /// let fruit = rng.weighted_choice(&["apple", "banana", "cherry"], &[1.0, 0.0, 3.0]);
+    ///
+    /// assert!(fruit == Some(&"apple") || fruit == Some(&"cherry"));
+    /// ```
+    #[inline]
+    pub fn weighted_choice<'a, T>(&mut self, items: &'a [T], weights: &[f64]) -> Option<&'a T> {
+        if items.len() != weights.len() {
+            return None;
+        }
+        self.gen_weighted_index(weights).map(|i| &items[i])
+    }
 
-        // Continue unless the iterator was exhausted
-        //
-        // note: this prevents iterators that "restart" from causing problems.
-        // If the iterator stops once, then so do we.
-        if reservoir.len() == amount {
-            for (i, elem) in iter.enumerate() {
-                let end = i + 1 + amount;
-                let k = self.usize(0..end);
-                if let Some(slot) = reservoir.get_mut(k) {
-                    *slot = elem;
+    /// Returns an endless iterator of random indices drawn from a cumulative frequency table.
+    ///
+    /// `cdf` holds running totals of integer frequencies: it must be sorted in non-decreasing
+    /// order, and its last element is the total frequency. Index `i` is yielded with
+    /// probability `(cdf[i] - cdf[i - 1]) / total`, taking `cdf[-1]` as zero. For example, the
+    /// frequencies `[3, 0, 5]` correspond to the table `[3, 3, 8]`.
+    ///
+    /// Each sample takes one random draw and a binary search, so it runs in `O(log(n))` time.
+    /// If `cdf` is not sorted, the distribution of the results is unspecified.
+    ///
+    /// Panics if `cdf` is empty or its total is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut rng = fastrand::Rng::new();
+    /// let samples: Vec<usize> = rng.weighted_samples(&[3, 3, 8]).take(100).collect();
+    ///
+    /// assert!(samples.iter().all(|&i| i == 0 || i == 2));
+    /// ```
+    pub fn weighted_samples<'a>(&'a mut self, cdf: &'a [u64]) -> impl Iterator<Item = usize> + 'a {
+        let total = match cdf.last() {
+            Some(&total) if total > 0 => total,
+            _ => panic!("cumulative frequency table must have a positive total"),
+        };
+
+        core::iter::repeat_with(move || {
+            let r = self.gen_mod_u64(total);
+
+            // Find the first bucket whose running total exceeds `r`.
+            let (mut lo, mut hi) = (0, cdf.len() - 1);
+            while lo < hi {
+                let mid = lo + (hi - lo) / 2;
+                if cdf[mid] > r {
+                    hi = mid;
+                } else {
+                    lo = mid + 1;
                 }
             }
-        } else {
-            // If less than one third of the `Vec` was used, reallocate
-            // so that the unused space is not wasted. There is a corner
-            // case where `amount` was much less than `self.len()`.
-            if reservoir.capacity() > 3 * reservoir.len() {
-                reservoir.shrink_to_fit();
-            }
+            lo
+        })
+    }
+
+    /// Draws one item at random, with probability proportional to its weight, and removes it.
+    ///
+    /// The item and its weight are removed with [`Vec::swap_remove`], so repeated calls draw
+    /// from a shrinking weighted set without replacement in `O(n)` time per draw.
+    ///
+    /// Returns `None` if `items` is empty, if any weight is negative or not finite, or if the
+    /// weights sum to zero. In that case both vectors are left untouched.
+    ///
+    /// Panics if `items` and `weights` have different lengths.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut rng = fastrand::Rng::new();
+    /// let mut cards = vec!["common", "uncommon", "rare"];
+    /// let mut weights = vec![10.0, 5.0, 1.0];
+    ///
+    /// while let Some(card) = rng.draw_weighted(&mut cards, &mut weights) {
+    ///     println!("drew {}", card);
+    /// }
+    /// assert!(cards.is_empty() && weights.is_empty());
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn draw_weighted<T>(&mut self, items: &mut Vec<T>, weights: &mut Vec<f64>) -> Option<T> {
+        if items.len() != weights.len() {
+            panic!("items and weights must have the same length");
         }
-        reservoir
+
+        let index = self.gen_weighted_index(weights)?;
+        weights.swap_remove(index);
+        Some(items.swap_remove(index))
     }
 
     rng_integer!(
         i8,
+        i8_inclusive,
         u8,
         gen_u32,
         gen_mod_u32,
@@ -427,6 +2393,7 @@ impl Rng {
 
     rng_integer!(
         i16,
+        i16_inclusive,
         u16,
         gen_u32,
         gen_mod_u32,
@@ -435,6 +2402,7 @@ impl Rng {
 
     rng_integer!(
         i32,
+        i32_inclusive,
         u32,
         gen_u32,
         gen_mod_u32,
@@ -443,6 +2411,7 @@ impl Rng {
 
     rng_integer!(
         i64,
+        i64_inclusive,
         u64,
         gen_u64,
         gen_mod_u64,
@@ -451,6 +2420,7 @@ impl Rng {
 
     rng_integer!(
         i128,
+        i128_inclusive,
         u128,
         gen_u128,
         gen_mod_u128,
@@ -460,28 +2430,167 @@ impl Rng {
     #[cfg(target_pointer_width = "16")]
     rng_integer!(
         isize,
+        isize_inclusive,
         usize,
         gen_u32,
         gen_mod_u32,
-        "Generates a random `isize` in the given range."
+        "Generates a random `isize` in the given range.\n\n\
+        Values are drawn the same way as for [`i64`](Rng::i64) on 64-bit targets, and as \
+        for [`i32`](Rng::i32) on others, so seeded output depends on the pointer width."
     );
     #[cfg(target_pointer_width = "32")]
     rng_integer!(
         isize,
+        isize_inclusive,
         usize,
         gen_u32,
         gen_mod_u32,
-        "Generates a random `isize` in the given range."
+        "Generates a random `isize` in the given range.\n\n\
+        Values are drawn the same way as for [`i64`](Rng::i64) on 64-bit targets, and as \
+        for [`i32`](Rng::i32) on others, so seeded output depends on the pointer width."
     );
     #[cfg(target_pointer_width = "64")]
     rng_integer!(
         isize,
+        isize_inclusive,
         usize,
         gen_u64,
         gen_mod_u64,
-        "Generates a random `isize` in the given range."
+        "Generates a random `isize` in the given range.\n\n\
+        Values are drawn the same way as for [`i64`](Rng::i64) on 64-bit targets, and as \
+        for [`i32`](Rng::i32) on others, so seeded output depends on the pointer width."
     );
 
+    /// Samples `amount` distinct indices from `0..len` into `out`.
+    ///
+    /// `out` is cleared first, and its allocation is reused, so sampling into the same vector
+    /// repeatedly does not allocate once it is large enough. The order of the indices is
+    /// unspecified.
+    ///
+    /// When `amount` is small compared to `len`, Floyd's algorithm is used, which takes
+    /// `O(amount^2)` time and only stores the sampled indices. Otherwise, `out` is filled with
+    /// `0..len` and partially shuffled, which takes `O(len)` time.
+    ///
+    /// Panics if `amount > len`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut rng = fastrand::Rng::new();
+    /// let mut indices = Vec::new();
+    /// rng.sample_indices(100, 5, &mut indices);
+    ///
+    /// assert_eq!(indices.len(), 5);
+    /// assert!(indices.iter().all(|&i| i < 100));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn sample_indices(&mut self, len: usize, amount: usize, out: &mut Vec<usize>) {
+        if amount > len {
+            panic!(
+                "cannot sample {} distinct indices from a length of {}",
+                amount, len
+            );
+        }
+
+        out.clear();
+        if amount.saturating_mul(amount) <= len {
+            // Floyd's algorithm picks distinct indices without rejection.
+            for j in len - amount..len {
+                let t = self.usize(..=j);
+                out.push(if out.contains(&t) { j } else { t });
+            }
+        } else {
+            out.extend(0..len);
+            self.partial_shuffle(out, amount);
+            out.truncate(amount);
+        }
+    }
+
+    /// Generates `count` distinct random values from the given range, sorted in ascending order.
+    ///
+    /// When `count` is a sizable fraction of the range, the values are produced directly in
+    /// order using selection sampling, which takes `O(len)` time where `len` is the size of the
+    /// range. For sparse samples, Floyd's algorithm is used instead, which takes `O(count *
+    /// log(count))` time regardless of the size of the range.
+    ///
+    /// Panics if `count` is larger than the number of values in the range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut rng = fastrand::Rng::new();
+    /// let keys = rng.sorted_unique_u64(5, 1..=100);
+    ///
+    /// assert_eq!(keys.len(), 5);
+    /// assert!(keys.windows(2).all(|w| w[0] < w[1]));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn sorted_unique_u64(&mut self, count: usize, range: impl RangeBounds<u64>) -> Vec<u64> {
+        let low = match range.start_bound() {
+            Bound::Unbounded => Some(u64::MIN),
+            Bound::Included(&x) => Some(x),
+            Bound::Excluded(&x) => x.checked_add(1),
+        };
+        let high = match range.end_bound() {
+            Bound::Unbounded => Some(u64::MAX),
+            Bound::Included(&x) => Some(x),
+            Bound::Excluded(&x) => x.checked_sub(1),
+        };
+        let len = match (low, high) {
+            (Some(low), Some(high)) if low <= high => u128::from(high - low) + 1,
+            _ => 0,
+        };
+
+        if count as u128 > len {
+            panic!(
+                "cannot choose {} unique values from a range of {} values",
+                count, len
+            );
+        }
+        if count == 0 {
+            return Vec::new();
+        }
+
+        let low = low.unwrap();
+        let mut keys = Vec::with_capacity(count);
+
+        if count as u128 * 16 >= len {
+            // Selection sampling (Knuth's Algorithm S): walk the range in order and pick each
+            // value with probability `needed / remaining`.
+            let mut remaining = len as u64;
+            let mut needed = count as u64;
+            let mut x = low;
+            while needed > 0 {
+                if self.gen_mod_u64(remaining) < needed {
+                    keys.push(x);
+                    needed -= 1;
+                }
+                remaining -= 1;
+                x = x.wrapping_add(1);
+            }
+        } else {
+            // Floyd's algorithm picks distinct offsets without rejection, and the set keeps them
+            // in order.
+            let mut chosen = BTreeSet::new();
+            let last = (len - 1) as u64;
+            for j in last - (count as u64 - 1)..=last {
+                let t = if j == u64::MAX {
+                    self.gen_u64()
+                } else {
+                    self.gen_mod_u64(j + 1)
+                };
+                if !chosen.insert(t) {
+                    chosen.insert(j);
+                }
+            }
+            keys.extend(chosen.into_iter().map(|offset| low + offset));
+        }
+
+        keys
+    }
+
     /// Generates a random `char` in range a-z.
     #[inline]
     pub fn lowercase(&mut self) -> char {
@@ -489,47 +2598,328 @@ impl Rng {
         *self.choice(CHARS).unwrap() as char
     }
 
-    /// Initializes this generator with the given seed.
+    /// Generates a random string of `len` characters in range a-z.
+    ///
+    /// This is equivalent to [`string`](Rng::string) with [`Charset::Lowercase`].
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    pub fn lowercase_string(&mut self, len: usize) -> String {
+        self.string(len, Charset::Lowercase)
+    }
+
+    /// Generates a random semantic version string, such as `1.4.2` or `0.3.0-beta.2+build.7`.
+    ///
+    /// Pre-release and build metadata are each included with probability 1/2. Use
+    /// [`semver_with`](Rng::semver_with) to control them explicitly.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn semver(&mut self) -> String {
+        let pre_release = self.bool();
+        let build = self.bool();
+        self.semver_with(pre_release, build)
+    }
+
+    /// Generates a random semantic version string with or without pre-release and build
+    /// metadata.
+    ///
+    /// The output always follows the [Semantic Versioning 2.0.0](https://semver.org) grammar:
+    /// the version core is `MAJOR.MINOR.PATCH`, pre-release identifiers are a label such as
+    /// `alpha` optionally followed by numeric identifiers without leading zeros, and build
+    /// metadata consists of alphanumeric identifiers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut rng = fastrand::Rng::new();
+    /// let version = rng.semver_with(true, false);
+    ///
+    /// assert!(version.contains('-'));
+    /// assert!(!version.contains('+'));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn semver_with(&mut self, pre_release: bool, build: bool) -> String {
+        use core::fmt::Write;
+
+        const LABELS: &[&str] = &["alpha", "beta", "rc", "dev", "pre"];
+
+        let mut s = String::new();
+        let (major, minor, patch) = (self.u32(..10), self.u32(..20), self.u32(..50));
+        write!(s, "{}.{}.{}", major, minor, patch).unwrap();
+
+        if pre_release {
+            s.push('-');
+            s.push_str(self.choice(LABELS).unwrap());
+            for _ in 0..self.usize(..3) {
+                write!(s, ".{}", self.u32(..100)).unwrap();
+            }
+        }
+
+        if build {
+            s.push('+');
+            for i in 0..self.usize(1..=2) {
+                if i > 0 {
+                    s.push('.');
+                }
+                if self.bool() {
+                    write!(s, "build.{}", self.u32(..1000)).unwrap();
+                } else {
+                    for _ in 0..self.usize(1..=8) {
+                        s.push(self.digit(16));
+                    }
+                }
+            }
+        }
+
+        s
+    }
+
+    /// Choose an item from an iterator at random.
+    ///
+    /// This function may have an unexpected result if the `len()` property of the
+    /// iterator does not match the actual number of items in the iterator. If
+    /// the iterator is empty, this returns `None`.
+    #[inline]
+    pub fn choice<I>(&mut self, iter: I) -> Option<I::Item>
+    where
+        I: IntoIterator,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let mut iter = iter.into_iter();
+
+        // Get the item at a random index.
+        let len = iter.len();
+        if len == 0 {
+            return None;
+        }
+        let index = self.usize(0..len);
+
+        iter.nth(index)
+    }
+
+    /// Chooses a random key of a map.
+    ///
+    /// Maps cannot be indexed, so this walks the map's iterator up to the chosen position and
+    /// takes `O(n)` time. Returns `None` if the map is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    ///
+    /// let mut rng = fastrand::Rng::new();
+    /// let mut stock = HashMap::new();
+    /// stock.insert("apple", 3);
+    /// stock.insert("pear", 5);
+    ///
+    /// let fruit = rng.choose_key(&stock).unwrap();
+    /// assert!(stock.contains_key(fruit));
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    pub fn choose_key<'a, K, V, S>(&mut self, map: &'a HashMap<K, V, S>) -> Option<&'a K> {
+        self.choice(map.keys())
+    }
+
+    /// Removes a random element from a vector and returns it.
+    ///
+    /// The element is removed with [`Vec::swap_remove`], so this takes `O(1)` time but does not
+    /// preserve the order of the remaining elements. Returns `None` if the vector is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut rng = fastrand::Rng::new();
+    /// let mut queue = vec!["a", "b", "c"];
+    ///
+    /// while let Some(job) = rng.swap_remove_random(&mut queue) {
+    ///     println!("running {}", job);
+    /// }
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    pub fn swap_remove_random<T>(&mut self, vec: &mut Vec<T>) -> Option<T> {
+        if vec.is_empty() {
+            return None;
+        }
+        let index = self.usize(..vec.len());
+        Some(vec.swap_remove(index))
+    }
+
+    /// Chooses a random element of a slice and returns a mutable reference to it.
+    ///
+    /// Returns `None` if the slice is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut rng = fastrand::Rng::new();
+    /// let mut v = [0; 4];
+    ///
+    /// if let Some(x) = rng.choose_mut(&mut v) {
+    ///     *x = 1;
+    /// }
+    /// assert_eq!(v.iter().sum::<i32>(), 1);
+    /// ```
+    #[inline]
+    pub fn choose_mut<'a, T>(&mut self, slice: &'a mut [T]) -> Option<&'a mut T> {
+        if slice.is_empty() {
+            return None;
+        }
+        let index = self.usize(..slice.len());
+        Some(&mut slice[index])
+    }
+
+    /// Chooses a random element of a slice and returns it along with its index.
+    ///
+    /// Returns `None` if the slice is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut rng = fastrand::Rng::new();
+    /// let v = ["a", "b", "c"];
+    ///
+    /// let (i, x) = rng.choose_with_index(&v).unwrap();
+    /// assert_eq!(v[i], *x);
+    /// ```
+    #[inline]
+    pub fn choose_with_index<'a, T>(&mut self, slice: &'a [T]) -> Option<(usize, &'a T)> {
+        if slice.is_empty() {
+            return None;
+        }
+        let index = self.usize(..slice.len());
+        Some((index, &slice[index]))
+    }
+
+    /// Shuffles a slice randomly.
+    #[inline]
+    pub fn shuffle<T>(&mut self, slice: &mut [T]) {
+        // Fisher-Yates, front to back: after step `i`, `slice[..=i]` is a uniformly random
+        // permutation of its original elements.
+        for i in 1..slice.len() {
+            slice.swap(i, self.usize(..=i));
+        }
+    }
+
+    /// Shuffles a [`VecDeque`] randomly.
+    ///
+    /// This works on the deque in place, even when its contents are not contiguous in memory.
+    /// The result is the same as shuffling the slice returned by
+    /// [`VecDeque::make_contiguous`] with [`shuffle`](Rng::shuffle).
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    pub fn shuffle_deque<T>(&mut self, deque: &mut VecDeque<T>) {
+        for i in 1..deque.len() {
+            deque.swap(i, self.usize(..=i));
+        }
+    }
+
+    /// Splits a slice into two at a random index.
+    ///
+    /// The index is chosen uniformly from `0..=len`, so either half may be empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut rng = fastrand::Rng::new();
+    /// let input = b"fuzz me";
+    /// let (head, tail) = rng.split_at_random(input);
+    ///
+    /// assert_eq!([head, tail].concat(), input);
+    /// ```
     #[inline]
-    pub fn seed(&mut self, seed: u64) {
-        self.0 = seed;
+    pub fn split_at_random<'a, T>(&mut self, slice: &'a [T]) -> (&'a [T], &'a [T]) {
+        slice.split_at(self.usize(..=slice.len()))
     }
 
-    /// Gives back **current** seed that is being held by this generator.
+    /// Splits a mutable slice into two at a random index.
+    ///
+    /// The index is chosen uniformly from `0..=len`, so either half may be empty.
     #[inline]
-    pub fn get_seed(&self) -> u64 {
-        self.0
+    pub fn split_at_mut_random<'a, T>(&mut self, slice: &'a mut [T]) -> (&'a mut [T], &'a mut [T]) {
+        let mid = self.usize(..=slice.len());
+        slice.split_at_mut(mid)
     }
 
-    /// Choose an item from an iterator at random.
+    /// Rotates a slice to the left by a random amount.
     ///
-    /// This function may have an unexpected result if the `len()` property of the
-    /// iterator does not match the actual number of items in the iterator. If
-    /// the iterator is empty, this returns `None`.
+    /// Unlike [`shuffle`](Rng::shuffle), this preserves the cyclic order of the elements. Every
+    /// rotation, including the identity, is equally likely.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut rng = fastrand::Rng::new();
+    /// let mut v = [1, 2, 3, 4];
+    /// rng.rotate_random(&mut v);
+    ///
+    /// let i = v.iter().position(|&x| x == 1).unwrap();
+    /// v.rotate_left(i);
+    /// assert_eq!(v, [1, 2, 3, 4]);
+    /// ```
     #[inline]
-    pub fn choice<I>(&mut self, iter: I) -> Option<I::Item>
-    where
-        I: IntoIterator,
-        I::IntoIter: ExactSizeIterator,
-    {
-        let mut iter = iter.into_iter();
-
-        // Get the item at a random index.
-        let len = iter.len();
-        if len == 0 {
-            return None;
+    pub fn rotate_random<T>(&mut self, slice: &mut [T]) {
+        if !slice.is_empty() {
+            let mid = self.usize(..slice.len());
+            slice.rotate_left(mid);
         }
-        let index = self.usize(0..len);
+    }
 
-        iter.nth(index)
+    /// Generates a random permutation of `0..n`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut rng = fastrand::Rng::new();
+    /// let order = rng.permutation(5);
+    ///
+    /// let mut sorted = order.clone();
+    /// sorted.sort();
+    /// assert_eq!(sorted, [0, 1, 2, 3, 4]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn permutation(&mut self, n: usize) -> Vec<usize> {
+        let mut permutation: Vec<usize> = (0..n).collect();
+        self.shuffle(&mut permutation);
+        permutation
     }
 
-    /// Shuffles a slice randomly.
+    /// Shuffles the first `amount` elements of a slice into a random sample of the whole slice.
+    ///
+    /// After this call, the first `amount` elements are a uniformly random sample of the slice,
+    /// in random order, and the rest of the elements are left in an unspecified order. The
+    /// sample and the rest are returned as two subslices. If `amount` is at least the length of
+    /// the slice, the whole slice is shuffled.
+    ///
+    /// Complexity is `O(amount)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut rng = fastrand::Rng::new();
+    /// let mut v: Vec<i32> = (0..100).collect();
+    /// let (sample, rest) = rng.partial_shuffle(&mut v, 3);
+    ///
+    /// assert_eq!(sample.len(), 3);
+    /// assert_eq!(rest.len(), 97);
+    /// ```
     #[inline]
-    pub fn shuffle<T>(&mut self, slice: &mut [T]) {
-        for i in 1..slice.len() {
-            slice.swap(i, self.usize(..=i));
+    pub fn partial_shuffle<'a, T>(
+        &mut self,
+        slice: &'a mut [T],
+        amount: usize,
+    ) -> (&'a mut [T], &'a mut [T]) {
+        let amount = amount.min(slice.len());
+        for i in 0..amount {
+            slice.swap(i, self.usize(i..slice.len()));
         }
+        slice.split_at_mut(amount)
     }
 
     /// Fill a byte slice with random data.
@@ -556,8 +2946,127 @@ impl Rng {
         }
     }
 
+    /// Fills a slice with values generated by `f`.
+    ///
+    /// The closure is called once for each element, in order, with exclusive access to this
+    /// generator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut rng = fastrand::Rng::new();
+    /// let mut points = [(0.0, 0.0); 16];
+    ///
+    /// rng.fill_with(&mut points, |rng| (rng.f64(), rng.f64()));
+    /// assert!(points.iter().all(|&(x, y)| x < 1.0 && y < 1.0));
+    /// ```
+    #[inline]
+    pub fn fill_with<T>(&mut self, slice: &mut [T], mut f: impl FnMut(&mut Self) -> T) {
+        for slot in slice {
+            *slot = f(self);
+        }
+    }
+
+    /// Generates an array of random bytes.
+    ///
+    /// This is equivalent to calling [`fill`](Rng::fill) on a zeroed array.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut rng = fastrand::Rng::new();
+    /// let key: [u8; 16] = rng.fill_array();
+    /// ```
+    #[inline]
+    pub fn fill_array<const N: usize>(&mut self) -> [u8; N] {
+        let mut array = [0u8; N];
+        self.fill(&mut array);
+        array
+    }
+
+    /// Generates a vector of `n` random bytes.
+    ///
+    /// This is equivalent to calling [`fill`](Rng::fill) on a zeroed vector. If `n` is zero, the
+    /// generator is not advanced.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut rng = fastrand::Rng::new();
+    /// let salt = rng.bytes(16);
+    ///
+    /// assert_eq!(salt.len(), 16);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn bytes(&mut self, n: usize) -> Vec<u8> {
+        let mut bytes = alloc::vec![0; n];
+        self.fill(&mut bytes);
+        bytes
+    }
+
+    /// Fill a `u32` slice with random data.
+    #[inline]
+    pub fn fill_u32(&mut self, slice: &mut [u32]) {
+        // Each block of WyRand output provides two values.
+        let mut chunks = slice.chunks_exact_mut(2);
+        for chunk in chunks.by_ref() {
+            let n = self.gen_u64();
+            chunk[0] = n as u32;
+            chunk[1] = (n >> 32) as u32;
+        }
+
+        for x in chunks.into_remainder() {
+            *x = self.gen_u32();
+        }
+    }
+
+    /// Fill a `u64` slice with random data.
+    #[inline]
+    pub fn fill_u64(&mut self, slice: &mut [u64]) {
+        for x in slice {
+            *x = self.gen_u64();
+        }
+    }
+
+    /// Fill a `bool` slice with random data.
+    ///
+    /// This uses all 64 bits of each block of output, rather than one call to
+    /// [`bool()`](Rng::bool) per element.
+    #[inline]
+    pub fn fill_bools(&mut self, slice: &mut [bool]) {
+        for chunk in slice.chunks_mut(64) {
+            let mut n = self.gen_u64();
+            for x in chunk {
+                *x = n & 1 == 1;
+                n >>= 1;
+            }
+        }
+    }
+
+    /// Fill a slice of plain-old-data values with random data.
+    ///
+    /// The slice is filled as raw bytes using [`fill`](Rng::fill), so every bit pattern of `T`
+    /// is possible. For floating-point types this includes NaN and infinities; use
+    /// [`f32`](Rng::f32) or [`f64`](Rng::f64) for values in `0..1`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut rng = fastrand::Rng::new();
+    /// let mut samples = [0u16; 64];
+    /// rng.fill_pod(&mut samples);
+    /// ```
+    #[cfg(feature = "bytemuck")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bytemuck")))]
+    #[inline]
+    pub fn fill_pod<T: bytemuck::Pod>(&mut self, slice: &mut [T]) {
+        self.fill(bytemuck::cast_slice_mut(slice));
+    }
+
     rng_integer!(
         u8,
+        u8_inclusive,
         u8,
         gen_u32,
         gen_mod_u32,
@@ -566,6 +3075,7 @@ impl Rng {
 
     rng_integer!(
         u16,
+        u16_inclusive,
         u16,
         gen_u32,
         gen_mod_u32,
@@ -574,6 +3084,7 @@ impl Rng {
 
     rng_integer!(
         u32,
+        u32_inclusive,
         u32,
         gen_u32,
         gen_mod_u32,
@@ -582,6 +3093,7 @@ impl Rng {
 
     rng_integer!(
         u64,
+        u64_inclusive,
         u64,
         gen_u64,
         gen_mod_u64,
@@ -590,6 +3102,7 @@ impl Rng {
 
     rng_integer!(
         u128,
+        u128_inclusive,
         u128,
         gen_u128,
         gen_mod_u128,
@@ -599,91 +3112,362 @@ impl Rng {
     #[cfg(target_pointer_width = "16")]
     rng_integer!(
         usize,
+        usize_inclusive,
         usize,
         gen_u32,
         gen_mod_u32,
-        "Generates a random `usize` in the given range."
+        "Generates a random `usize` in the given range.\n\n\
+        Values are drawn the same way as for [`u64`](Rng::u64) on 64-bit targets, and as \
+        for [`u32`](Rng::u32) on others, so seeded output depends on the pointer width."
     );
     #[cfg(target_pointer_width = "32")]
     rng_integer!(
         usize,
+        usize_inclusive,
         usize,
         gen_u32,
         gen_mod_u32,
-        "Generates a random `usize` in the given range."
+        "Generates a random `usize` in the given range.\n\n\
+        Values are drawn the same way as for [`u64`](Rng::u64) on 64-bit targets, and as \
+        for [`u32`](Rng::u32) on others, so seeded output depends on the pointer width."
     );
     #[cfg(target_pointer_width = "64")]
     rng_integer!(
         usize,
+        usize_inclusive,
         usize,
         gen_u64,
         gen_mod_u64,
-        "Generates a random `usize` in the given range."
+        "Generates a random `usize` in the given range.\n\n\
+        Values are drawn the same way as for [`u64`](Rng::u64) on 64-bit targets, and as \
+        for [`u32`](Rng::u32) on others, so seeded output depends on the pointer width."
     );
 
-    /// Generates a random `char` in range A-Z.
-    #[inline]
-    pub fn uppercase(&mut self) -> char {
-        const CHARS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ";
-        *self.choice(CHARS).unwrap() as char
-    }
+    rng_nonzero!(
+        nonzero_u8,
+        u8,
+        NonZeroU8,
+        "Generates a random [`NonZeroU8`]."
+    );
 
-    /// Generates a random `char` in the given range.
+    rng_nonzero!(
+        nonzero_u16,
+        u16,
+        NonZeroU16,
+        "Generates a random [`NonZeroU16`]."
+    );
+
+    rng_nonzero!(
+        nonzero_u32,
+        u32,
+        NonZeroU32,
+        "Generates a random [`NonZeroU32`]."
+    );
+
+    rng_nonzero!(
+        nonzero_u64,
+        u64,
+        NonZeroU64,
+        "Generates a random [`NonZeroU64`]."
+    );
+
+    rng_nonzero!(
+        nonzero_u128,
+        u128,
+        NonZeroU128,
+        "Generates a random [`NonZeroU128`]."
+    );
+
+    rng_nonzero!(
+        nonzero_usize,
+        usize,
+        NonZeroUsize,
+        "Generates a random [`NonZeroUsize`]."
+    );
+
+    rng_parity!(
+        even_u32,
+        u32,
+        u32,
+        0,
+        "Generates a random even `u32` in the given range."
+    );
+
+    rng_parity!(
+        odd_u32,
+        u32,
+        u32,
+        1,
+        "Generates a random odd `u32` in the given range."
+    );
+
+    rng_parity!(
+        even_u64,
+        u64,
+        u64,
+        0,
+        "Generates a random even `u64` in the given range."
+    );
+
+    rng_parity!(
+        odd_u64,
+        u64,
+        u64,
+        1,
+        "Generates a random odd `u64` in the given range."
+    );
+
+    rng_parity!(
+        even_usize,
+        usize,
+        usize,
+        0,
+        "Generates a random even `usize` in the given range."
+    );
+
+    rng_parity!(
+        odd_usize,
+        usize,
+        usize,
+        1,
+        "Generates a random odd `usize` in the given range."
+    );
+
+    rng_parity!(
+        even_i32,
+        i32,
+        u32,
+        0,
+        "Generates a random even `i32` in the given range."
+    );
+
+    rng_parity!(
+        odd_i32,
+        i32,
+        u32,
+        1,
+        "Generates a random odd `i32` in the given range."
+    );
+
+    rng_parity!(
+        even_i64,
+        i64,
+        u64,
+        0,
+        "Generates a random even `i64` in the given range."
+    );
+
+    rng_parity!(
+        odd_i64,
+        i64,
+        u64,
+        1,
+        "Generates a random odd `i64` in the given range."
+    );
+
+    /// Generates a random multiple of `n` in the given range.
     ///
-    /// Panics if the range is empty.
+    /// The result is uniformly distributed over the multiples of `n` in the range, including
+    /// zero if the range contains it.
+    ///
+    /// Panics if `n` is zero or if the range contains no multiples of `n`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut rng = fastrand::Rng::new();
+    /// let size = rng.multiple_of(4096, 1..=1 << 20);
+    ///
+    /// assert_eq!(size % 4096, 0);
+    /// assert!((4096..=1 << 20).contains(&size));
+    /// ```
     #[inline]
-    pub fn char(&mut self, range: impl RangeBounds<char>) -> char {
+    pub fn multiple_of(&mut self, n: u64, range: impl RangeBounds<u64>) -> u64 {
+        if n == 0 {
+            panic!("cannot generate multiples of zero");
+        }
         let panic_empty_range = || {
             panic!(
-                "empty range: {:?}..{:?}",
+                "no multiples of {} in range: {:?}..{:?}",
+                n,
                 range.start_bound(),
                 range.end_bound()
             )
         };
 
-        let surrogate_start = 0xd800u32;
-        let surrogate_len = 0x800u32;
-
         let low = match range.start_bound() {
-            Bound::Unbounded => 0u8 as char,
+            Bound::Unbounded => u64::MIN,
             Bound::Included(&x) => x,
-            Bound::Excluded(&x) => {
-                let scalar = if x as u32 == surrogate_start - 1 {
-                    surrogate_start + surrogate_len
-                } else {
-                    x as u32 + 1
-                };
-                char::try_from(scalar).unwrap_or_else(|_| panic_empty_range())
-            }
+            Bound::Excluded(&x) => x.checked_add(1).unwrap_or_else(panic_empty_range),
         };
 
         let high = match range.end_bound() {
-            Bound::Unbounded => core::char::MAX,
+            Bound::Unbounded => u64::MAX,
             Bound::Included(&x) => x,
-            Bound::Excluded(&x) => {
-                let scalar = if x as u32 == surrogate_start + surrogate_len {
-                    surrogate_start - 1
-                } else {
-                    (x as u32).wrapping_sub(1)
-                };
-                char::try_from(scalar).unwrap_or_else(|_| panic_empty_range())
-            }
+            Bound::Excluded(&x) => x.checked_sub(1).unwrap_or_else(panic_empty_range),
         };
 
-        if low > high {
+        // Sample the quotient, from the first multiple at or above `low` to the last one at or
+        // below `high`.
+        let first = low / n + (low % n != 0) as u64;
+        let last = high / n;
+        if low > high || first > last {
             panic_empty_range();
         }
+        self.u64(first..=last) * n
+    }
+
+    /// Generates a random `char` in range A-Z.
+    #[inline]
+    pub fn uppercase(&mut self) -> char {
+        const CHARS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+        *self.choice(CHARS).unwrap() as char
+    }
+
+    /// Generates a random string of `len` characters in range A-Z.
+    ///
+    /// This is equivalent to [`string`](Rng::string) with [`Charset::Uppercase`].
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    pub fn uppercase_string(&mut self, len: usize) -> String {
+        self.string(len, Charset::Uppercase)
+    }
+
+    /// Generates a random version 7 UUID for the given Unix timestamp in milliseconds.
+    ///
+    /// The layout follows [RFC 9562](https://www.rfc-editor.org/rfc/rfc9562#name-uuid-version-7):
+    ///
+    /// - bytes 0-5: the low 48 bits of `unix_ms`, big-endian,
+    /// - byte 6: the version `7` in the high nibble, followed by 4 random bits,
+    /// - byte 7: 8 random bits,
+    /// - byte 8: the variant `0b10` in the two high bits, followed by 6 random bits,
+    /// - bytes 9-15: 56 random bits.
+    ///
+    /// UUIDs generated for later timestamps sort after earlier ones when compared as bytes.
+    pub fn uuid_v7_from_millis(&mut self, unix_ms: u64) -> [u8; 16] {
+        let mut bytes = [0u8; 16];
+        self.fill(&mut bytes[6..]);
+        bytes[..6].copy_from_slice(&unix_ms.to_be_bytes()[2..]);
+        bytes[6] = (bytes[6] & 0x0f) | 0x70;
+        bytes[8] = (bytes[8] & 0x3f) | 0x80;
+        bytes
+    }
+
+    /// Generates a random version 7 UUID using the current system time.
+    ///
+    /// See [`uuid_v7_from_millis`](Rng::uuid_v7_from_millis) for the layout. If the system
+    /// clock is set before the Unix epoch, the timestamp is zero.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn uuid_v7(&mut self) -> [u8; 16] {
+        use std::time::{SystemTime, UNIX_EPOCH};
+
+        let unix_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or(0);
+        self.uuid_v7_from_millis(unix_ms)
+    }
+
+    /// Generates a random version 7 UUID using the current system time, formatted as a string.
+    ///
+    /// The string uses the canonical hyphenated lowercase form, such as
+    /// `017f22e2-79b0-7cc3-98c4-dc0c0c07398f`.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn uuid_v7_string(&mut self) -> std::string::String {
+        const HEX: &[u8] = b"0123456789abcdef";
+
+        let bytes = self.uuid_v7();
+        let mut s = std::string::String::with_capacity(36);
+        for (i, &b) in bytes.iter().enumerate() {
+            if i == 4 || i == 6 || i == 8 || i == 10 {
+                s.push('-');
+            }
+            s.push(HEX[usize::from(b >> 4)] as char);
+            s.push(HEX[usize::from(b & 0x0f)] as char);
+        }
+        s
+    }
+
+    /// Generates a random UTF-8 string that is exactly `byte_len` bytes long.
+    ///
+    /// The length is measured in bytes, not `char`s: the returned string's [`len()`] always
+    /// equals `byte_len`. Each `char` is generated by first picking an encoded width of 1 to 4
+    /// bytes that fits into the remaining budget, then picking a uniform `char` of that width.
+    /// Any Unicode scalar value may appear, including control characters.
+    ///
+    /// [`len()`]: alloc::string::String::len
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut rng = fastrand::Rng::new();
+    /// let s = rng.utf8_string_bytes(10);
+    ///
+    /// assert_eq!(s.len(), 10);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn utf8_string_bytes(&mut self, byte_len: usize) -> String {
+        let mut s = String::with_capacity(byte_len);
+        let mut remaining = byte_len;
+        while remaining > 0 {
+            let width = self.usize(1..=remaining.min(4));
+            let c = match width {
+                1 => self.char('\u{0}'..='\u{7f}'),
+                2 => self.char('\u{80}'..='\u{7ff}'),
+                3 => self.char('\u{800}'..='\u{ffff}'),
+                _ => self.char('\u{10000}'..=core::char::MAX),
+            };
+            s.push(c);
+            remaining -= width;
+        }
+        s
+    }
+
+    /// Generates a random `char` in the given range.
+    ///
+    /// The result is uniformly distributed over the Unicode scalar values in the range. The
+    /// surrogate code points `0xd800..0xe000` are not scalar values, so a range that spans them
+    /// skips over them.
+    ///
+    /// Panics if the range is empty.
+    #[inline]
+    pub fn char(&mut self, range: impl RangeBounds<char>) -> char {
+        let (low, high) = char::resolve_bounds(&range).unwrap_or_else(|| {
+            panic!(
+                "empty range: {:?}..{:?}",
+                range.start_bound(),
+                range.end_bound()
+            )
+        });
+        self.char_inclusive(low, high)
+    }
 
-        let gap = if (low as u32) < surrogate_start && (high as u32) >= surrogate_start {
-            surrogate_len
+    /// Generates a random `char` in `low..=high`, where `low <= high`.
+    #[inline]
+    fn char_inclusive(&mut self, low: char, high: char) -> char {
+        let gap = if (low as u32) < SURROGATE_START && (high as u32) >= SURROGATE_START {
+            SURROGATE_LEN
         } else {
             0
         };
         let range = high as u32 - low as u32 - gap;
         let mut val = self.u32(0..=range) + low as u32;
-        if val >= surrogate_start {
+        if val >= SURROGATE_START {
             val += gap;
         }
         val.try_into().unwrap()
     }
+
+    /// Generates a random `char` in the given range, or returns `None` if the range is empty.
+    ///
+    /// For a non-empty range, the result is the same as that of [`char`](Rng::char).
+    #[inline]
+    pub fn try_char(&mut self, range: impl RangeBounds<char>) -> Option<char> {
+        let (low, high) = char::resolve_bounds(&range)?;
+        Some(self.char_inclusive(low, high))
+    }
 }
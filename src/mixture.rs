@@ -0,0 +1,86 @@
+//! Mixtures of arbitrary distributions.
+
+use crate::Rng;
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// A component distribution of a [`Mixture`].
+///
+/// This is a boxed closure that draws a value using the given generator.
+pub type Component = Box<dyn Fn(&mut Rng) -> f64>;
+
+/// A sampler for a weighted mixture of component distributions.
+///
+/// Each sample first picks a component with probability proportional to its weight, then draws
+/// a value from that component.
+///
+/// # Example
+///
+/// Model a bimodal latency, where most requests are fast but some hit a slow path:
+///
+/// ```
+/// use fastrand::{Mixture, Rng};
+///
+/// let latency = Mixture::new(vec![
+///     (0.9, Box::new(|rng: &mut Rng| 1.0 + rng.f64())),
+///     (0.1, Box::new(|rng: &mut Rng| 50.0 + 10.0 * rng.f64())),
+/// ]);
+///
+/// let mut rng = Rng::new();
+/// let x = latency.sample(&mut rng);
+/// assert!((1.0..2.0).contains(&x) || (50.0..60.0).contains(&x));
+/// ```
+pub struct Mixture {
+    weights: Vec<f64>,
+    components: Vec<Component>,
+}
+
+impl Mixture {
+    /// Creates a mixture from `(weight, component)` pairs.
+    ///
+    /// Panics if `components` is empty or if any weight is not positive and finite.
+    pub fn new(components: Vec<(f64, Component)>) -> Mixture {
+        if components.is_empty() {
+            panic!("mixture must have at least one component");
+        }
+
+        let mut weights = Vec::with_capacity(components.len());
+        let mut samplers = Vec::with_capacity(components.len());
+        for (weight, sampler) in components {
+            if weight <= 0.0 || !weight.is_finite() {
+                panic!("mixture weights must be positive and finite: {:?}", weight);
+            }
+            weights.push(weight);
+            samplers.push(sampler);
+        }
+
+        Mixture {
+            weights,
+            components: samplers,
+        }
+    }
+
+    /// Returns the weight of each component.
+    #[inline]
+    pub fn weights(&self) -> &[f64] {
+        &self.weights
+    }
+
+    /// Draws a random value from the mixture.
+    pub fn sample(&self, rng: &mut Rng) -> f64 {
+        let index = rng
+            .gen_weighted_index(&self.weights)
+            .expect("mixture weights are validated on construction");
+        (self.components[index])(rng)
+    }
+}
+
+impl fmt::Debug for Mixture {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Mixture")
+            .field("weights", &self.weights)
+            .finish()
+    }
+}
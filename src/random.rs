@@ -0,0 +1,116 @@
+//! Generic generation of primitive values.
+
+use crate::{Backend, Rng};
+
+/// Types that can be generated at random without any parameters.
+///
+/// This trait is implemented for the primitive types supported by [`Rng`], and is used by
+/// [`Rng::gen`] to generate values of a type chosen by generic code:
+///
+/// - Integers take any value of the type.
+/// - `bool` is `true` or `false` with equal probability.
+/// - `f32` and `f64` are in range `0..1`.
+/// - `char` is any Unicode scalar value.
+///
+/// # Deriving
+///
+/// With the `derive` feature, this trait can be derived for structs and enums. A derived struct
+/// has every field generated with its own `Random` implementation. A derived enum first picks
+/// one of its variants uniformly, and then generates the fields of that variant in the same way.
+///
+/// ```
+/// # #[cfg(feature = "derive")]
+/// # {
+/// use fastrand::{Random, Rng};
+///
+/// #[derive(Random, Debug, PartialEq)]
+/// enum Suit {
+///     Clubs,
+///     Diamonds,
+///     Hearts,
+///     Spades,
+/// }
+///
+/// #[derive(Random, Debug)]
+/// struct Card {
+///     suit: Suit,
+///     rank: u8,
+/// }
+///
+/// let mut rng = Rng::new();
+/// let card: Card = rng.gen();
+/// # let _ = card;
+/// # }
+/// ```
+///
+/// The derive has a few limitations:
+///
+/// - Every variant is equally likely, regardless of how many fields it has or of their types.
+/// - Every field type, and every type parameter, must implement `Random`. Fields cannot be
+///   restricted to a range.
+/// - Enums without variants and unions are not supported.
+/// - A recursive type, such as a linked list, may recurse without bound.
+pub trait Random {
+    /// Generates a random value.
+    fn random<B: Backend>(rng: &mut Rng<B>) -> Self;
+}
+
+macro_rules! random_primitive {
+    ($($t:tt),*) => {$(
+        impl Random for $t {
+            #[inline]
+            fn random<B: Backend>(rng: &mut Rng<B>) -> $t {
+                rng.$t(..)
+            }
+        }
+    )*};
+}
+
+random_primitive!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, char);
+
+impl Random for bool {
+    #[inline]
+    fn random<B: Backend>(rng: &mut Rng<B>) -> bool {
+        rng.bool()
+    }
+}
+
+impl Random for f32 {
+    #[inline]
+    fn random<B: Backend>(rng: &mut Rng<B>) -> f32 {
+        rng.f32()
+    }
+}
+
+impl Random for f64 {
+    #[inline]
+    fn random<B: Backend>(rng: &mut Rng<B>) -> f64 {
+        rng.f64()
+    }
+}
+
+impl<B: Backend> Rng<B> {
+    /// Generates a random value of type `T`.
+    ///
+    /// See [`Random`] for the values generated for each type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fastrand::{Random, Rng};
+    ///
+    /// fn pair<T: Random>(rng: &mut Rng) -> (T, T) {
+    ///     (rng.gen(), rng.gen())
+    /// }
+    ///
+    /// let mut rng = Rng::new();
+    /// let (a, b): (u8, u8) = pair(&mut rng);
+    /// let x: f64 = rng.gen();
+    /// assert!((0.0..1.0).contains(&x));
+    /// # let _ = (a, b);
+    /// ```
+    #[inline]
+    pub fn gen<T: Random>(&mut self) -> T {
+        T::random(self)
+    }
+}
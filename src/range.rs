@@ -0,0 +1,162 @@
+//! Generic sampling of numbers in a range.
+
+use crate::{Backend, ResolveBounds, Rng};
+
+use core::ops::RangeBounds;
+
+/// Numeric types that can be generated uniformly in a range.
+///
+/// This trait is implemented for all primitive integer and floating-point types and is used by
+/// [`Rng::range`] to generate values of a type chosen by generic code. Floats are generated with
+/// [`Rng::f32_range`] and [`Rng::f64_range`].
+pub trait RandomRange: Sized {
+    /// Generates a random value in the given range.
+    ///
+    /// Panics if the range is empty. For floats, also panics if a bound is NaN or an included
+    /// bound is infinite.
+    fn random_range<B: Backend, R: RangeBounds<Self>>(rng: &mut Rng<B>, range: R) -> Self;
+
+    /// Generates a random value in the given range, or returns `None` where
+    /// [`random_range`](RandomRange::random_range) would panic.
+    ///
+    /// Otherwise, the result is the same as that of `random_range`.
+    fn try_random_range<B: Backend, R: RangeBounds<Self>>(
+        rng: &mut Rng<B>,
+        range: R,
+    ) -> Option<Self>;
+}
+
+macro_rules! random_range {
+    ($($t:tt => $range:ident, $inclusive:ident;)*) => {$(
+        impl RandomRange for $t {
+            #[inline]
+            fn random_range<B: Backend, R: RangeBounds<$t>>(rng: &mut Rng<B>, range: R) -> $t {
+                rng.$range(range)
+            }
+
+            #[inline]
+            fn try_random_range<B: Backend, R: RangeBounds<$t>>(
+                rng: &mut Rng<B>,
+                range: R,
+            ) -> Option<$t> {
+                let (low, high) = $t::resolve_bounds(&range)?;
+                Some(rng.$inclusive(low, high))
+            }
+        }
+    )*};
+}
+
+random_range! {
+    u8 => u8, u8_inclusive;
+    u16 => u16, u16_inclusive;
+    u32 => u32, u32_inclusive;
+    u64 => u64, u64_inclusive;
+    u128 => u128, u128_inclusive;
+    usize => usize, usize_inclusive;
+    i8 => i8, i8_inclusive;
+    i16 => i16, i16_inclusive;
+    i32 => i32, i32_inclusive;
+    i64 => i64, i64_inclusive;
+    i128 => i128, i128_inclusive;
+    isize => isize, isize_inclusive;
+    f32 => f32_range, f32_inclusive;
+    f64 => f64_range, f64_inclusive;
+}
+
+impl<B: Backend> Rng<B> {
+    /// Generates a random number of type `T` in the given range.
+    ///
+    /// This is equivalent to calling the method for the type, such as [`u32`](Rng::u32) or
+    /// [`f64_range`](Rng::f64_range), and is useful in code that is generic over the type.
+    ///
+    /// Panics if the range is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fastrand::{RandomRange, Rng};
+    ///
+    /// fn roll<T: RandomRange + From<u8>>(rng: &mut Rng) -> T {
+    ///     rng.range(T::from(1)..=T::from(6))
+    /// }
+    ///
+    /// let mut rng = Rng::new();
+    /// let a: u32 = roll(&mut rng);
+    /// let b: i64 = roll(&mut rng);
+    /// assert!((1..=6).contains(&a));
+    /// assert!((1..=6).contains(&b));
+    /// ```
+    #[inline]
+    pub fn range<T: RandomRange>(&mut self, range: impl RangeBounds<T>) -> T {
+        T::random_range(self, range)
+    }
+
+    /// Generates a random number of type `T` in the given range.
+    ///
+    /// This is the same as [`range`](Rng::range), under the name used by the [`rand`] crate,
+    /// for convenience when migrating code from it. Integers and floats are supported.
+    ///
+    /// With the `rand_core` feature, this inherent method takes precedence over
+    /// `rand::Rng::gen_range` when both are in scope.
+    ///
+    /// Panics if the range is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut rng = fastrand::Rng::new();
+    ///
+    /// let die: u8 = rng.gen_range(1..=6);
+    /// let offset = rng.gen_range(-10i32..10);
+    /// let scale = rng.gen_range(0.5..1.5);
+    ///
+    /// assert!((1..=6).contains(&die));
+    /// assert!((-10..10).contains(&offset));
+    /// assert!((0.5..1.5).contains(&scale));
+    /// ```
+    ///
+    /// [`rand`]: https://crates.io/crates/rand
+    #[inline]
+    pub fn gen_range<T: RandomRange>(&mut self, range: impl RangeBounds<T>) -> T {
+        self.range(range)
+    }
+
+    /// Generates a random number of type `T` in the given range, or returns `None` if the range
+    /// is empty.
+    ///
+    /// For floats, `None` is also returned if a bound is NaN or an included bound is infinite.
+    /// Otherwise, the result is the same as that of [`range`](Rng::range), so this is a
+    /// non-panicking alternative to it for ranges that come from untrusted input.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut rng = fastrand::Rng::new();
+    ///
+    /// assert_eq!(rng.try_range(5u32..5), None);
+    /// assert!(rng.try_range(-3i64..=3).is_some());
+    /// ```
+    #[inline]
+    pub fn try_range<T: RandomRange>(&mut self, range: impl RangeBounds<T>) -> Option<T> {
+        T::try_random_range(self, range)
+    }
+
+    /// Generates a random number of type `T` in the given range, or returns `default` if the
+    /// range is empty.
+    ///
+    /// This is the same as [`try_range`](Rng::try_range) followed by
+    /// [`unwrap_or`](Option::unwrap_or).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut rng = fastrand::Rng::new();
+    ///
+    /// let (low, high) = (10u32, 5);
+    /// assert_eq!(rng.range_or(low..high, 7), 7);
+    /// ```
+    #[inline]
+    pub fn range_or<T: RandomRange>(&mut self, range: impl RangeBounds<T>, default: T) -> T {
+        self.try_range(range).unwrap_or(default)
+    }
+}
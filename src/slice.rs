@@ -0,0 +1,110 @@
+//! Extension methods for slices.
+
+use crate::{Backend, Rng};
+
+/// Extension methods for randomly choosing and shuffling slice elements.
+///
+/// This trait is implemented for all slices and provides method-call syntax for the
+/// corresponding methods of [`Rng`].
+///
+/// # Example
+///
+/// ```
+/// use fastrand::{Rng, SliceExt};
+///
+/// let mut rng = Rng::new();
+/// let mut v = [1, 2, 3, 4, 5];
+///
+/// v.shuffle(&mut rng);
+/// let x = v.choose(&mut rng).unwrap();
+/// assert!((1..=5).contains(x));
+/// ```
+pub trait SliceExt {
+    /// The type of the elements of the slice.
+    type Item;
+
+    /// Shuffles the slice randomly.
+    ///
+    /// See [`Rng::shuffle`].
+    fn shuffle<B: Backend>(&mut self, rng: &mut Rng<B>);
+
+    /// Chooses a random element, or returns `None` if the slice is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fastrand::{Rng, SliceExt};
+    ///
+    /// let mut rng = Rng::new();
+    /// assert_eq!([7].choose(&mut rng), Some(&7));
+    /// assert_eq!(<[i32]>::choose(&[], &mut rng), None);
+    /// ```
+    fn choose<B: Backend>(&self, rng: &mut Rng<B>) -> Option<&Self::Item>;
+
+    /// Chooses a random element and returns a mutable reference to it, or returns `None` if the
+    /// slice is empty.
+    ///
+    /// See [`Rng::choose_mut`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fastrand::{Rng, SliceExt};
+    ///
+    /// let mut rng = Rng::new();
+    /// let mut v = [0; 3];
+    /// *v.choose_mut(&mut rng).unwrap() += 1;
+    ///
+    /// assert_eq!(v.iter().sum::<i32>(), 1);
+    /// ```
+    fn choose_mut<B: Backend>(&mut self, rng: &mut Rng<B>) -> Option<&mut Self::Item>;
+
+    /// Shuffles the first `amount` elements into a random sample of the whole slice.
+    ///
+    /// See [`Rng::partial_shuffle`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fastrand::{Rng, SliceExt};
+    ///
+    /// let mut rng = Rng::new();
+    /// let mut v = [1, 2, 3, 4, 5];
+    /// let (sample, rest) = v.partial_shuffle(&mut rng, 2);
+    ///
+    /// assert_eq!((sample.len(), rest.len()), (2, 3));
+    /// ```
+    fn partial_shuffle<B: Backend>(
+        &mut self,
+        rng: &mut Rng<B>,
+        amount: usize,
+    ) -> (&mut [Self::Item], &mut [Self::Item]);
+}
+
+impl<T> SliceExt for [T] {
+    type Item = T;
+
+    #[inline]
+    fn shuffle<B: Backend>(&mut self, rng: &mut Rng<B>) {
+        rng.shuffle(self)
+    }
+
+    #[inline]
+    fn choose<B: Backend>(&self, rng: &mut Rng<B>) -> Option<&T> {
+        rng.choice(self)
+    }
+
+    #[inline]
+    fn choose_mut<B: Backend>(&mut self, rng: &mut Rng<B>) -> Option<&mut T> {
+        rng.choose_mut(self)
+    }
+
+    #[inline]
+    fn partial_shuffle<B: Backend>(
+        &mut self,
+        rng: &mut Rng<B>,
+        amount: usize,
+    ) -> (&mut [T], &mut [T]) {
+        rng.partial_shuffle(self, amount)
+    }
+}
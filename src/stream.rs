@@ -0,0 +1,61 @@
+//! An endless iterator that owns its generator.
+
+use crate::{Backend, Rng, Wyrand};
+
+use core::iter::FusedIterator;
+
+/// An endless iterator of random `u64`s that owns its generator.
+///
+/// This is created by [`Rng::into_u64_stream`]. Unlike [`Rng::iter_u64`], it does not borrow
+/// the generator, so it can be stored or passed to code that requires `'static` iterators.
+///
+/// The iterator never returns `None`, so it must be bounded, for example with
+/// [`Iterator::take`], before being collected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RngStream<B = Wyrand>(Rng<B>);
+
+impl<B: Backend> RngStream<B> {
+    /// Returns the generator, which continues where the stream left off.
+    #[inline]
+    pub fn into_inner(self) -> Rng<B> {
+        self.0
+    }
+}
+
+impl<B: Backend> Iterator for RngStream<B> {
+    type Item = u64;
+
+    #[inline]
+    fn next(&mut self) -> Option<u64> {
+        Some(self.0.next_u64())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<B: Backend> FusedIterator for RngStream<B> {}
+
+impl<B: Backend> Rng<B> {
+    /// Converts this generator into an endless iterator of random `u64`s.
+    ///
+    /// The iterator yields the same values as repeated calls to [`next_u64`](Rng::next_u64).
+    /// It never ends, so callers must bound it, for example with [`Iterator::take`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// fn sum_of(values: impl Iterator<Item = u64> + 'static) -> u128 {
+    ///     values.take(4).map(u128::from).sum()
+    /// }
+    ///
+    /// let stream = fastrand::Rng::with_seed(7).into_u64_stream();
+    /// println!("{}", sum_of(stream));
+    /// ```
+    #[inline]
+    pub fn into_u64_stream(self) -> RngStream<B> {
+        RngStream(self)
+    }
+}
@@ -0,0 +1,225 @@
+//! Precomputed weighted sampling.
+
+use crate::{Backend, Rng};
+
+use alloc::vec::Vec;
+use core::fmt;
+
+/// A precomputed discrete distribution for sampling indices in `O(1)` time.
+///
+/// The tables are built with Vose's alias method in `O(n)` time, after which every sample takes
+/// one `usize` draw and one `f64` draw, regardless of the number of weights. This makes it a
+/// better fit than [`Rng::weighted_choice`] when sampling repeatedly from the same weights.
+///
+/// A copy of the weights is kept in their input precision for [`WeightedIndex::update`], so
+/// the distribution takes 20 bytes per weight when created with [`WeightedIndex::new_f32`] and
+/// 24 bytes per weight with [`WeightedIndex::new`], on 64-bit targets.
+///
+/// # Example
+///
+/// ```
+/// use fastrand::{Rng, WeightedIndex};
+///
+/// let items = ["common", "uncommon", "rare"];
+/// let dist = WeightedIndex::new(&[10.0, 5.0, 1.0]).unwrap();
+///
+/// let mut rng = Rng::new();
+/// println!("drew {}", items[dist.sample(&mut rng)]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct WeightedIndex {
+    /// The weights the tables were built from, kept for [`WeightedIndex::update`].
+    weights: Weights,
+    /// The probability of keeping each column instead of taking its alias.
+    prob: Vec<f64>,
+    alias: Vec<usize>,
+}
+
+impl WeightedIndex {
+    /// Creates a distribution that samples each index with probability proportional to its
+    /// weight.
+    ///
+    /// Returns an error if `weights` is empty, if any weight is negative or not finite, or if the
+    /// weights sum to zero.
+    pub fn new(weights: &[f64]) -> Result<WeightedIndex, WeightedError> {
+        WeightedIndex::build(Weights::F64(weights.to_vec()))
+    }
+
+    /// Creates a distribution from `f32` weights.
+    ///
+    /// This is the same as [`WeightedIndex::new`], but avoids converting large weight tables to
+    /// `f64` up front.
+    pub fn new_f32(weights: &[f32]) -> Result<WeightedIndex, WeightedError> {
+        WeightedIndex::build(Weights::F32(weights.to_vec()))
+    }
+
+    fn build(weights: Weights) -> Result<WeightedIndex, WeightedError> {
+        let mut dist = WeightedIndex {
+            weights,
+            prob: Vec::new(),
+            alias: Vec::new(),
+        };
+        match &dist.weights {
+            Weights::F64(w) => rebuild(w, &mut dist.prob, &mut dist.alias)?,
+            Weights::F32(w) => rebuild(w, &mut dist.prob, &mut dist.alias)?,
+        }
+        Ok(dist)
+    }
+
+    /// Changes the weight of the index `index`.
+    ///
+    /// The alias method has no cheap way to change a single weight, so this rebuilds the tables
+    /// in `O(n)` time, reusing their allocations. It is meant for weights that change
+    /// occasionally between many samples.
+    ///
+    /// If the distribution was created with [`WeightedIndex::new_f32`], `weight` is converted
+    /// to `f32`.
+    ///
+    /// Returns an error if `weight` is negative or not finite, or if the weights would sum to
+    /// zero. The distribution is left unchanged in that case.
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fastrand::{Rng, WeightedIndex};
+    ///
+    /// let mut dist = WeightedIndex::new(&[1.0, 0.0]).unwrap();
+    /// dist.update(0, 0.0).unwrap_err();
+    ///
+    /// dist.update(1, 1.0).unwrap();
+    /// dist.update(0, 0.0).unwrap();
+    /// assert_eq!(dist.sample(&mut Rng::new()), 1);
+    /// ```
+    pub fn update(&mut self, index: usize, weight: f64) -> Result<(), WeightedError> {
+        match &mut self.weights {
+            Weights::F64(w) => update(w, index, weight, &mut self.prob, &mut self.alias),
+            Weights::F32(w) => update(w, index, weight as f32, &mut self.prob, &mut self.alias),
+        }
+    }
+
+    /// Samples a random index.
+    #[inline]
+    pub fn sample<B: Backend>(&self, rng: &mut Rng<B>) -> usize {
+        let i = rng.usize(..self.prob.len());
+        if rng.f64() < self.prob[i] {
+            i
+        } else {
+            self.alias[i]
+        }
+    }
+}
+
+/// The weights of a [`WeightedIndex`], in the precision they were given in.
+#[derive(Debug, Clone)]
+enum Weights {
+    F64(Vec<f64>),
+    F32(Vec<f32>),
+}
+
+/// Sets `weights[index]` to `weight` and rebuilds the tables, restoring the old weight on error.
+fn update<W: Copy + Into<f64>>(
+    weights: &mut [W],
+    index: usize,
+    weight: W,
+    prob: &mut Vec<f64>,
+    alias: &mut Vec<usize>,
+) -> Result<(), WeightedError> {
+    let old = core::mem::replace(&mut weights[index], weight);
+    let result = rebuild(weights, prob, alias);
+    if result.is_err() {
+        weights[index] = old;
+    }
+    result
+}
+
+/// Builds the alias tables from `weights`.
+///
+/// The weights are validated before anything is written, so on error the tables are left as
+/// they were.
+fn rebuild<W: Copy + Into<f64>>(
+    weights: &[W],
+    prob: &mut Vec<f64>,
+    alias: &mut Vec<usize>,
+) -> Result<(), WeightedError> {
+    if weights.is_empty() {
+        return Err(WeightedError::NoItem);
+    }
+
+    let mut total = 0.0;
+    for &w in weights {
+        let w: f64 = w.into();
+        if w < 0.0 || !w.is_finite() {
+            return Err(WeightedError::InvalidWeight);
+        }
+        total += w;
+    }
+    if total <= 0.0 {
+        return Err(WeightedError::AllWeightsZero);
+    }
+    if !total.is_finite() {
+        return Err(WeightedError::InvalidWeight);
+    }
+
+    // Scale the weights so that they average to 1, then pair up columns below the average
+    // with columns above it.
+    let n = weights.len();
+    prob.clear();
+    prob.extend(weights.iter().map(|&w| w.into() * n as f64 / total));
+    alias.clear();
+    alias.extend(0..n);
+
+    let mut small = Vec::new();
+    let mut large = Vec::new();
+    for (i, &p) in prob.iter().enumerate() {
+        if p < 1.0 {
+            small.push(i);
+        } else {
+            large.push(i);
+        }
+    }
+
+    while let (Some(&s), Some(&l)) = (small.last(), large.last()) {
+        small.pop();
+        alias[s] = l;
+        prob[l] = (prob[l] + prob[s]) - 1.0;
+        if prob[l] < 1.0 {
+            large.pop();
+            small.push(l);
+        }
+    }
+
+    // Whatever is left over is only off from 1 due to rounding.
+    for i in small.into_iter().chain(large) {
+        prob[i] = 1.0;
+    }
+
+    Ok(())
+}
+
+/// An error returned when creating a [`WeightedIndex`] from invalid weights.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WeightedError {
+    /// There are no weights.
+    NoItem,
+
+    /// A weight is negative or not finite, or the weights overflow when summed.
+    InvalidWeight,
+
+    /// All of the weights are zero.
+    AllWeightsZero,
+}
+
+impl fmt::Display for WeightedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            WeightedError::NoItem => "no weights provided",
+            WeightedError::InvalidWeight => "a weight is negative or not finite",
+            WeightedError::AllWeightsZero => "all weights are zero",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for WeightedError {}
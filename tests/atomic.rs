@@ -0,0 +1,38 @@
+#![cfg(feature = "atomic")]
+
+use fastrand::AtomicRng;
+
+#[test]
+fn matches_fork_stream() {
+    let shared = AtomicRng::with_seed(42);
+    let mut rng = fastrand::Rng::with_seed(42);
+
+    for _ in 0..10 {
+        assert_eq!(shared.fork(), rng.fork());
+    }
+    assert_eq!(shared.get_seed(), rng.get_seed());
+}
+
+#[test]
+fn multithreaded() {
+    static RNG: AtomicRng = AtomicRng::with_seed(0x4d59_5df4_d0f3_3173);
+
+    let handles: Vec<_> = (0..8)
+        .map(|_| {
+            std::thread::spawn(|| {
+                for _ in 0..1000 {
+                    assert!(RNG.u8(10..20) >= 10);
+                    assert!(RNG.i64(-5..=5).abs() <= 5);
+                    assert!(RNG.usize(..3) < 3);
+                    let x = RNG.f64();
+                    assert!((0.0..1.0).contains(&x));
+                    RNG.bool();
+                }
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+}
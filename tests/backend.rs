@@ -0,0 +1,47 @@
+use fastrand::{Backend, Pcg32, Rng, Wyrand};
+
+#[test]
+fn pcg32_reference_output() {
+    // Output of the `pcg32-demo` program from the reference implementation.
+    let mut pcg = Pcg32::new(42, 54);
+    let expected = [
+        0xa15c_02b7,
+        0x7b47_f409,
+        0xba1d_3330,
+        0x83d2_f293,
+        0xbfa4_784b,
+        0xcbed_606e,
+    ];
+    for &x in &expected {
+        assert_eq!(pcg.next_u32(), x);
+    }
+}
+
+#[test]
+fn default_backend_is_wyrand() {
+    let mut a = Rng::with_seed(7);
+    let mut b = Rng::from_backend(Wyrand::seed_from_u64(7));
+    for _ in 0..100 {
+        assert_eq!(a.u64(..), b.u64(..));
+    }
+    assert_eq!(a, b);
+}
+
+#[test]
+fn pcg32_methods() {
+    let mut rng = Rng::from_backend(Pcg32::seed_from_u64(1));
+
+    for _ in 0..1000 {
+        assert!((10..20).contains(&rng.u8(10..20)));
+        assert!((-3..=3).contains(&rng.i128(-3..=3)));
+        assert!((0.0..1.0).contains(&rng.f64()));
+    }
+
+    let mut v: Vec<u32> = (0..50).collect();
+    rng.shuffle(&mut v);
+    v.sort_unstable();
+    assert_eq!(v, (0..50).collect::<Vec<_>>());
+
+    let mut child = rng.fork();
+    assert_ne!(child.u64(..), rng.u64(..));
+}
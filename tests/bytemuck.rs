@@ -0,0 +1,27 @@
+#![cfg(feature = "bytemuck")]
+
+#[test]
+fn fill_pod() {
+    let mut a = fastrand::Rng::with_seed(7);
+    let mut b = fastrand::Rng::with_seed(7);
+
+    let mut words = [0u32; 16];
+    let mut bytes = [0u8; 64];
+    a.fill_pod(&mut words);
+    b.fill(&mut bytes);
+    assert_eq!(bytemuck::cast_slice::<u32, u8>(&words), &bytes[..]);
+
+    // Odd lengths go through the same path, with a partial final block.
+    let mut halves = [0u16; 7];
+    let mut bytes = [0u8; 14];
+    a.fill_pod(&mut halves);
+    b.fill(&mut bytes);
+    assert_eq!(bytemuck::cast_slice::<u16, u8>(&halves), &bytes[..]);
+
+    let mut floats = [0.0f32; 5];
+    let mut bytes = [0u8; 20];
+    a.fill_pod(&mut floats);
+    b.fill(&mut bytes);
+    assert_eq!(bytemuck::cast_slice::<f32, u8>(&floats), &bytes[..]);
+    assert_eq!(a, b);
+}
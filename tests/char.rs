@@ -1,5 +1,5 @@
 use std::convert::TryFrom;
-use std::ops::RangeBounds;
+use std::ops::{Bound, RangeBounds};
 
 fn test_char_coverage<R>(n: usize, range: R)
 where
@@ -42,3 +42,193 @@ fn test_char() {
 
     test_char_coverage(100, penultimate_private..=last_private);
 }
+
+#[test]
+fn char_surrogate_gap() {
+    let mut rng = fastrand::Rng::with_seed(6);
+    let surrogates = 0xd800u32..0xe000;
+
+    // The full range skips the surrogates, and splits the remaining scalars proportionally.
+    let n = 1_000_000;
+    let mut below = 0;
+    for _ in 0..n {
+        let c = rng.char(..);
+        assert!(!surrogates.contains(&(c as u32)), "{:?}", c);
+        if (c as u32) < surrogates.start {
+            below += 1;
+        }
+    }
+    let expected = n as f64 * 0xd800 as f64 / (0x11_0000 - 0x800) as f64;
+    assert!((below as f64 - expected).abs() < 5.0 * expected.sqrt());
+
+    // The two scalars on either side of the gap are equally likely.
+    let mut counts = [0u32; 2];
+    for _ in 0..n {
+        match rng.char('\u{d7ff}'..='\u{e000}') {
+            '\u{d7ff}' => counts[0] += 1,
+            '\u{e000}' => counts[1] += 1,
+            c => panic!("{:?}", c),
+        }
+    }
+    let half = n as f64 / 2.0;
+    assert!((counts[0] as f64 - half).abs() < 5.0 * half.sqrt() / 2.0);
+
+    // Excluded bounds next to the gap step over it.
+    for _ in 0..1000 {
+        let c = rng.char((Bound::Excluded('\u{d7ff}'), Bound::Included('\u{e001}')));
+        assert!(c == '\u{e000}' || c == '\u{e001}');
+        let c = rng.char('\u{d7fe}'..'\u{e000}');
+        assert!(c == '\u{d7fe}' || c == '\u{d7ff}');
+    }
+
+    // A range straddling the gap is uniform over its 32 scalars.
+    let low = 0xd7f0u32;
+    let mut counts = [0u32; 32];
+    for _ in 0..320_000 {
+        let c = rng.char('\u{d7f0}'..='\u{e00f}') as u32;
+        let i = if c < surrogates.start {
+            c - low
+        } else {
+            c - low - 0x800
+        };
+        counts[i as usize] += 1;
+    }
+    let expected = 10_000.0;
+    let chi_squared: f64 = counts
+        .iter()
+        .map(|&x| (x as f64 - expected).powi(2) / expected)
+        .sum();
+    // The 99.9th percentile of the chi-squared distribution with 31 degrees of freedom.
+    assert!(chi_squared < 61.1, "chi-squared = {}", chi_squared);
+}
+
+#[test]
+fn utf8_string_bytes() {
+    let mut r = fastrand::Rng::new();
+    let mut widths = [false; 4];
+
+    for len in 0..200 {
+        let s = r.utf8_string_bytes(len);
+        assert_eq!(s.len(), len);
+        for c in s.chars() {
+            widths[c.len_utf8() - 1] = true;
+        }
+    }
+    assert_eq!(widths, [true; 4]);
+}
+
+#[test]
+fn ascii_printable() {
+    let mut rng = fastrand::Rng::with_seed(5);
+    for _ in 0..1000 {
+        let c = rng.ascii_printable();
+        assert!(c.is_ascii_graphic() || c == ' ');
+    }
+
+    let s = fastrand::ascii_printable_string(500);
+    assert_eq!(s.len(), 500);
+    assert!(s.chars().all(|c| c.is_ascii_graphic() || c == ' '));
+}
+
+#[test]
+fn char_from() {
+    use std::collections::HashSet;
+
+    let mut rng = fastrand::Rng::with_seed(9);
+    let mut covered = HashSet::new();
+    for _ in 0..200 {
+        let c = rng.char_from("αβγδ");
+        assert!("αβγδ".contains(c));
+        covered.insert(c);
+    }
+    assert_eq!(covered.len(), 4);
+
+    assert_eq!(fastrand::char_from("x"), 'x');
+}
+
+#[test]
+#[should_panic]
+fn char_from_empty() {
+    fastrand::char_from("");
+}
+
+#[test]
+fn string_charsets() {
+    use fastrand::Charset;
+
+    let mut rng = fastrand::Rng::with_seed(2);
+    let mut check = |charset: Charset, expected: fn(char) -> bool| {
+        let s = rng.string(1000, charset);
+        assert_eq!(s.len(), 1000);
+        assert!(s.chars().all(expected), "{:?}: {}", charset, s);
+    };
+
+    check(Charset::Alphabetic, |c| c.is_ascii_alphabetic());
+    check(Charset::Alphanumeric, |c| c.is_ascii_alphanumeric());
+    check(Charset::Lowercase, |c| c.is_ascii_lowercase());
+    check(Charset::Uppercase, |c| c.is_ascii_uppercase());
+    check(Charset::Hex, |c| {
+        c.is_ascii_digit() || ('a'..='f').contains(&c)
+    });
+    check(Charset::AsciiPrintable, |c| {
+        c.is_ascii_graphic() || c == ' '
+    });
+
+    assert_eq!(rng.string(0, Charset::Hex), "");
+    assert_eq!(fastrand::string(3, Charset::Uppercase).len(), 3);
+}
+
+#[test]
+fn case_strings() {
+    let mut rng = fastrand::Rng::with_seed(3);
+    for len in [0, 1, 17, 1000].iter().copied() {
+        let s = rng.lowercase_string(len);
+        assert_eq!(s.len(), len);
+        assert!(s.chars().all(|c| c.is_ascii_lowercase()), "{}", s);
+
+        let s = fastrand::uppercase_string(len);
+        assert_eq!(s.len(), len);
+        assert!(s.chars().all(|c| c.is_ascii_uppercase()), "{}", s);
+    }
+}
+
+#[test]
+fn random_case() {
+    let mut rng = fastrand::Rng::with_seed(3);
+    for &c in &['q', 'Q'] {
+        let mut seen = (false, false);
+        for _ in 0..100 {
+            match rng.random_case(c) {
+                'q' => seen.0 = true,
+                'Q' => seen.1 = true,
+                other => panic!("unexpected {:?}", other),
+            }
+        }
+        assert_eq!(seen, (true, true));
+    }
+
+    let seed = rng.get_seed();
+    for &c in &['7', ' ', 'é', '-'] {
+        assert_eq!(rng.random_case(c), c);
+    }
+    assert_eq!(rng.get_seed(), seed);
+    assert_eq!(fastrand::random_case('0'), '0');
+}
+
+#[test]
+fn digits() {
+    let mut rng = fastrand::Rng::with_seed(4);
+    for base in 2..=36 {
+        let s = rng.digits(base, 200);
+        assert_eq!(s.len(), 200);
+        assert!(s.chars().all(|c| c.is_digit(base)), "base {}: {}", base, s);
+    }
+    assert_eq!(rng.digits(1, 5), "00000");
+    assert_eq!(fastrand::digits(10, 0), "");
+}
+
+#[test]
+#[should_panic]
+fn digits_invalid_base() {
+    fastrand::Rng::new().digits(37, 0);
+}
@@ -0,0 +1,68 @@
+#![cfg(feature = "derive")]
+
+use fastrand::{Random, Rng};
+
+#[derive(Random, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+#[derive(Random, Debug, PartialEq)]
+enum Shape {
+    Point,
+    Circle(f64),
+    Rect { width: u8, height: u8 },
+}
+
+#[derive(Random, Debug)]
+struct Wrapper<T>(T, bool);
+
+#[derive(Random, Debug, PartialEq)]
+struct Unit;
+
+#[test]
+fn fieldless_enum() {
+    let mut rng = Rng::with_seed(1);
+    let mut counts = std::collections::HashMap::new();
+    for _ in 0..40_000 {
+        *counts.entry(rng.gen::<Direction>()).or_insert(0) += 1;
+    }
+    assert_eq!(counts.len(), 4);
+    assert!(counts.values().all(|&c| (9_500..10_500).contains(&c)));
+}
+
+#[test]
+fn data_variants() {
+    let mut rng = Rng::with_seed(2);
+    let mut seen = [false; 3];
+    for _ in 0..100 {
+        match Shape::random(&mut rng) {
+            Shape::Point => seen[0] = true,
+            Shape::Circle(r) => {
+                assert!((0.0..1.0).contains(&r));
+                seen[1] = true;
+            }
+            Shape::Rect { .. } => seen[2] = true,
+        }
+    }
+    assert_eq!(seen, [true; 3]);
+}
+
+#[test]
+fn structs() {
+    let mut rng = Rng::with_seed(3);
+    let w: Wrapper<Direction> = rng.gen();
+    let _ = (w.0, w.1);
+    assert_eq!(rng.gen::<Unit>(), Unit);
+}
+
+#[test]
+#[cfg(not(target_family = "wasm"))]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/derive/pass_*.rs");
+    t.compile_fail("tests/derive/fail_*.rs");
+}
@@ -0,0 +1,6 @@
+use fastrand::Random;
+
+#[derive(Random)]
+enum Never {}
+
+fn main() {}
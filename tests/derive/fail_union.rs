@@ -0,0 +1,9 @@
+use fastrand::Random;
+
+#[derive(Random)]
+union Bits {
+    int: u32,
+    float: f32,
+}
+
+fn main() {}
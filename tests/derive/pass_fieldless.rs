@@ -0,0 +1,14 @@
+use fastrand::{Random, Rng};
+
+#[derive(Random)]
+enum Coin {
+    Heads,
+    Tails,
+}
+
+fn main() {
+    let mut rng = Rng::with_seed(7);
+    match Coin::random(&mut rng) {
+        Coin::Heads | Coin::Tails => {}
+    }
+}
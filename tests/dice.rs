@@ -0,0 +1,63 @@
+use fastrand::{DiceError, Rng};
+
+#[test]
+fn valid_notation() {
+    let mut rng = Rng::with_seed(6);
+    let cases: &[(&str, i64, i64)] = &[
+        ("1d20", 1, 20),
+        ("d20", 1, 20),
+        ("3d6", 3, 18),
+        ("3D6+2", 5, 20),
+        ("2d4-10", -8, -2),
+        (" 4d1 ", 4, 4),
+        ("0d6+3", 3, 3),
+    ];
+
+    for &(notation, min, max) in cases {
+        let mut seen = (false, false);
+        for _ in 0..2000 {
+            let roll = rng.dice(notation).unwrap();
+            assert!((min..=max).contains(&roll), "{}: {}", notation, roll);
+            seen.0 |= roll == min;
+            seen.1 |= roll == max;
+        }
+        assert_eq!(seen, (true, true), "{}", notation);
+    }
+
+    assert!((2..=12).contains(&fastrand::dice("2d6").unwrap()));
+}
+
+#[test]
+fn invalid_notation() {
+    let mut rng = Rng::with_seed(6);
+    for notation in &[
+        "", "d", "3d", "3x6", "d6+", "d6+-1", "+2d6", "2d+6", "2d6+1+1", "-1d6", "1.5d6",
+    ] {
+        assert_eq!(
+            rng.dice(notation),
+            Err(DiceError::Malformed),
+            "{}",
+            notation
+        );
+    }
+    assert_eq!(rng.dice("2d0"), Err(DiceError::ZeroSides));
+    assert_eq!(
+        rng.dice("1d6+9223372036854775807"),
+        Err(DiceError::Overflow)
+    );
+    assert_eq!(rng.dice("99999999999d6"), Err(DiceError::Overflow));
+    assert_eq!(
+        rng.dice("1d6-9223372036854775809"),
+        Err(DiceError::Overflow)
+    );
+    assert_eq!(rng.dice("1001d6"), Err(DiceError::TooManyDice));
+    assert_eq!(rng.dice("4294967295d6"), Err(DiceError::TooManyDice));
+}
+
+#[test]
+fn extreme_values() {
+    let mut rng = Rng::with_seed(6);
+    assert_eq!(rng.dice("0d6-9223372036854775808"), Ok(i64::MIN));
+    assert_eq!(rng.dice("1d1-9223372036854775808"), Ok(i64::MIN + 1));
+    assert_eq!(rng.dice("1000d1"), Ok(1000));
+}
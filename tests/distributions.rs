@@ -0,0 +1,165 @@
+/// Returns the sample mean and variance of `n` values produced by `f`.
+fn mean_and_variance(n: usize, mut f: impl FnMut() -> f64) -> (f64, f64) {
+    let samples: Vec<f64> = (0..n).map(|_| f()).collect();
+    let mean = samples.iter().sum::<f64>() / n as f64;
+    let variance = samples.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / n as f64;
+    (mean, variance)
+}
+
+#[test]
+fn normal() {
+    let mut r = fastrand::Rng::with_seed(1);
+    let (mean, variance) = mean_and_variance(100_000, || r.normal(5.0, 2.0));
+    assert!((mean - 5.0).abs() < 0.05, "mean = {}", mean);
+    assert!((variance - 4.0).abs() < 0.1, "variance = {}", variance);
+
+    assert_eq!(r.normal(3.0, 0.0), 3.0);
+
+    let (mean, _) = mean_and_variance(10_000, || fastrand::normal(-1.0, 1.0));
+    assert!((mean + 1.0).abs() < 0.1, "mean = {}", mean);
+}
+
+#[test]
+fn exp() {
+    let mut r = fastrand::Rng::with_seed(2);
+    let (mean, variance) = mean_and_variance(100_000, || r.exp(4.0));
+    assert!((mean - 0.25).abs() < 0.005, "mean = {}", mean);
+    assert!((variance - 0.0625).abs() < 0.005, "variance = {}", variance);
+
+    for _ in 0..1000 {
+        let x = fastrand::exp(1.0);
+        assert!(x >= 0.0 && x.is_finite());
+    }
+}
+
+#[test]
+fn geometric() {
+    let mut r = fastrand::Rng::with_seed(3);
+    let (mean, _) = mean_and_variance(100_000, || r.geometric(0.2) as f64);
+    assert!((mean - 4.0).abs() < 0.1, "mean = {}", mean);
+
+    let (mean, _) = mean_and_variance(100_000, || r.geometric(1e-9) as f64);
+    assert!((mean / 1e9 - 1.0).abs() < 0.02, "mean = {}", mean);
+
+    assert_eq!(r.geometric(1.0), 0);
+    assert_eq!(fastrand::geometric(2.0), 0);
+}
+
+#[test]
+#[should_panic]
+fn geometric_zero() {
+    fastrand::geometric(0.0);
+}
+
+#[test]
+fn angle() {
+    let mut r = fastrand::Rng::with_seed(4);
+    for _ in 0..1000 {
+        let a = r.angle();
+        assert!((0.0..2.0 * std::f64::consts::PI).contains(&a));
+    }
+    let (mean, _) = mean_and_variance(100_000, fastrand::angle);
+    assert!(
+        (mean - std::f64::consts::PI).abs() < 0.05,
+        "mean = {}",
+        mean
+    );
+}
+
+#[test]
+fn unit_vector_2d() {
+    let mut r = fastrand::Rng::with_seed(5);
+    let mut quadrants = [0u32; 4];
+    for _ in 0..40_000 {
+        let (x, y) = r.unit_vector_2d();
+        assert!((x * x + y * y - 1.0).abs() < 1e-9);
+        quadrants[(x < 0.0) as usize * 2 + (y < 0.0) as usize] += 1;
+    }
+    assert!(quadrants.iter().all(|&q| (9_500..10_500).contains(&q)));
+
+    let (x, y) = fastrand::unit_vector_2d();
+    assert!((x.hypot(y) - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn unit_vector_3d() {
+    let mut r = fastrand::Rng::with_seed(6);
+    let n = 100_000;
+    let (mut sx, mut sy, mut sz) = (0.0, 0.0, 0.0);
+    let mut northern_cap = 0;
+    for _ in 0..n {
+        let (x, y, z) = r.unit_vector_3d();
+        assert!((x * x + y * y + z * z - 1.0).abs() < 1e-9);
+        sx += x;
+        sy += y;
+        sz += z;
+        // The cap above z = 0.5 covers a quarter of the sphere's surface.
+        if z > 0.5 {
+            northern_cap += 1;
+        }
+    }
+    let n = n as f64;
+    assert!((sx / n).abs() < 0.01 && (sy / n).abs() < 0.01 && (sz / n).abs() < 0.01);
+    assert!((f64::from(northern_cap) / n - 0.25).abs() < 0.01);
+
+    let (x, y, z) = fastrand::unit_vector_3d();
+    assert!((x * x + y * y + z * z - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn normal_spare_cache() {
+    let mut r = fastrand::Rng::with_seed(7);
+    let start = r.get_seed();
+
+    // The second value of a pair comes from the cache, without advancing the state.
+    r.normal(0.0, 1.0);
+    let after_first = r.get_seed();
+    assert_ne!(after_first, start);
+    let copy = r.clone();
+    assert_eq!(copy, r);
+    r.normal(0.0, 1.0);
+    assert_eq!(r.get_seed(), after_first);
+    assert_ne!(copy, r);
+
+    // The cached value is a standard normal, scaled on use.
+    let mut a = copy.clone();
+    let mut b = copy;
+    assert_eq!(a.normal(10.0, 2.0), 10.0 + 2.0 * b.normal(0.0, 1.0));
+
+    // Reseeding clears the cache.
+    let mut c = fastrand::Rng::with_seed(7);
+    let x = c.normal(0.0, 1.0);
+    c.seed(7);
+    assert_eq!(c.normal(0.0, 1.0), x);
+    assert_eq!(c, {
+        let mut d = fastrand::Rng::with_seed(7);
+        d.normal(0.0, 1.0);
+        d
+    });
+}
+
+#[test]
+fn normal_spare_not_serialized() {
+    // The cache costs one word on top of the Wyrand state.
+    assert_eq!(std::mem::size_of::<fastrand::Rng>(), 16);
+
+    let mut rng = fastrand::Rng::with_seed(8);
+    rng.normal(0.0, 1.0);
+
+    // Text and byte round trips keep the state but drop the pending value.
+    let mut parsed: fastrand::Rng = rng.to_string().parse().unwrap();
+    let mut from_bytes = fastrand::Rng::from_bytes(rng.to_bytes());
+    assert_ne!(parsed, rng);
+    assert_eq!(parsed, from_bytes);
+    assert_eq!(parsed.get_seed(), rng.get_seed());
+
+    let mut fresh = fastrand::Rng::with_seed(rng.get_seed());
+    let expected = fresh.normal(0.0, 1.0);
+    assert_ne!(rng.clone().normal(0.0, 1.0), expected);
+    assert_eq!(parsed.normal(0.0, 1.0), expected);
+    assert_eq!(from_bytes.normal(0.0, 1.0), expected);
+
+    // Once the pending value is used, the round trip is exact again.
+    rng.normal(0.0, 1.0);
+    assert_eq!(rng.to_string().parse::<fastrand::Rng>().unwrap(), rng);
+}
@@ -0,0 +1,153 @@
+use std::ops::Bound;
+
+#[test]
+fn f64_range() {
+    let mut r = fastrand::Rng::new();
+
+    for _ in 0..10_000 {
+        let x = r.f64_range(-2.5..7.5);
+        assert!((-2.5..7.5).contains(&x));
+
+        let x = r.f64_range(1.0..=2.0);
+        assert!((1.0..=2.0).contains(&x));
+
+        let x = r.f64_range(..);
+        assert!(x.is_finite());
+
+        let x = fastrand::f64_range(0.0..1e-300);
+        assert!((0.0..1e-300).contains(&x));
+    }
+
+    assert_eq!(r.f64_range(3.0..=3.0), 3.0);
+}
+
+#[test]
+fn f32_range() {
+    let mut r = fastrand::Rng::new();
+
+    for _ in 0..10_000 {
+        let x = r.f32_range(10.0..20.0);
+        assert!((10.0..20.0).contains(&x));
+
+        let x = fastrand::f32_range(..=0.0);
+        assert!(x <= 0.0 && x.is_finite());
+    }
+}
+
+#[test]
+fn float_range_boundaries() {
+    let mut r = fastrand::Rng::new();
+
+    // Ranges that are only one or two values wide never return an excluded bound.
+    let next = f64::from_bits(1.0f64.to_bits() + 1);
+    let after = f64::from_bits(1.0f64.to_bits() + 2);
+    for _ in 0..1000 {
+        assert_eq!(r.f64_range(1.0..next), 1.0);
+        assert_eq!(
+            r.f64_range((Bound::Excluded(1.0), Bound::Included(next))),
+            next
+        );
+        assert_eq!(
+            r.f64_range((Bound::Excluded(1.0), Bound::Excluded(after))),
+            next
+        );
+        assert_eq!(r.f32_range(0.0..f32::from_bits(1)), 0.0);
+        assert_eq!(r.f64_range(f64::MAX..), f64::MAX);
+    }
+}
+
+#[test]
+fn f64_precise() {
+    let step = 1.0 / (1u64 << 53) as f64;
+    assert_eq!(step, f64::EPSILON / 2.0);
+
+    let mut rng = fastrand::Rng::with_seed(17);
+    let mut raw = rng.clone();
+    let mut odd = 0;
+    for _ in 0..10_000 {
+        let x = rng.f64_precise();
+        assert!((0.0..1.0).contains(&x));
+
+        // Every result is a multiple of the smallest nonzero value, 2^-53.
+        let k = x / step;
+        assert_eq!(k.fract(), 0.0);
+        assert_eq!(k as u64, raw.next_u64() >> 11);
+        if k as u64 % 2 == 1 {
+            odd += 1;
+        }
+    }
+    // About half the results are not representable by `f64()`, which has 52 bits.
+    assert!((4_500..5_500).contains(&odd));
+}
+
+#[test]
+fn f64_open_closed() {
+    let mut rng = fastrand::Rng::with_seed(18);
+    for _ in 0..100_000 {
+        let x = rng.f64_open();
+        assert!(x > 0.0 && x < 1.0);
+        assert!(x.ln().is_finite());
+
+        let y = rng.f64_closed();
+        assert!((0.0..=1.0).contains(&y));
+    }
+
+    // The bounds of the open interval are representable and stay inside it.
+    let min = 0.5 / (1u64 << 52) as f64;
+    let max = ((1u64 << 52) as f64 - 0.5) / (1u64 << 52) as f64;
+    assert!(min > 0.0 && max < 1.0);
+}
+
+#[test]
+fn unit_float_guarantees() {
+    let mut rng = fastrand::Rng::with_seed(19);
+    for _ in 0..1_000_000 {
+        let x = rng.f64();
+        assert!(x.is_finite() && !x.is_sign_negative());
+        assert!(x == 0.0 || x.is_normal());
+        assert!(x < 1.0);
+
+        let y = rng.f32();
+        assert!(y.is_finite() && !y.is_sign_negative());
+        assert!(y == 0.0 || y.is_normal());
+        assert!(y < 1.0);
+    }
+}
+
+#[test]
+fn float_range_excluding() {
+    let mut r = fastrand::Rng::with_seed(20);
+
+    // A range a few values wide, where the excluded window covers 0.5 and the value above it.
+    let step = f64::EPSILON / 2.0;
+    let high = 0.5 + 4.0 * step;
+    let mut seen = [false; 5];
+    for _ in 0..10_000 {
+        let x = r.f64_range_excluding(0.5..=high, 0.5);
+        assert!((0.5..=high).contains(&x));
+        assert!((x - 0.5).abs() > f64::EPSILON * 0.5, "{}", x);
+        seen[((x - 0.5) / step) as usize] = true;
+    }
+    assert_eq!(seen, [false, false, true, true, true]);
+
+    for _ in 0..10_000 {
+        let x = r.f64_range_excluding(-1.0..1.0, 0.0);
+        assert!(x != 0.0 && (-1.0..1.0).contains(&x));
+
+        let x = r.f32_range_excluding(0.0..f32::from_bits(3), 0.0);
+        assert!(x == f32::from_bits(1) || x == f32::from_bits(2));
+
+        // NaN and infinite exclusions reject nothing.
+        let x = r.f64_range_excluding(1.0..=1.0, f64::NAN);
+        assert_eq!(x, 1.0);
+        let x = r.f64_range_excluding(..=1.0, f64::NEG_INFINITY);
+        assert!(x.is_finite());
+    }
+}
+
+#[test]
+#[should_panic]
+fn float_range_excluding_everything() {
+    let one = f64::from_bits(1.0f64.to_bits() + 1);
+    fastrand::Rng::new().f64_range_excluding(1.0..=one, 1.0);
+}
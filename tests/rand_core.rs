@@ -0,0 +1,41 @@
+#![cfg(feature = "rand_core")]
+
+use rand::seq::SliceRandom;
+use rand::{Rng as _, RngCore, SeedableRng};
+
+#[test]
+fn rng_core() {
+    let mut a = fastrand::Rng::with_seed(7);
+    let mut b = fastrand::Rng::with_seed(7);
+
+    assert_eq!(RngCore::next_u64(&mut a), b.u64(..));
+    assert_eq!(RngCore::next_u32(&mut a), b.u32(..));
+
+    let mut x = [0u8; 13];
+    let mut y = [0u8; 13];
+    a.fill_bytes(&mut x);
+    b.fill(&mut y);
+    assert_eq!(x, y);
+}
+
+#[test]
+fn seedable_rng() {
+    let a = fastrand::Rng::from_seed(7u64.to_le_bytes());
+    let b = fastrand::Rng::seed_from_u64(7);
+    assert_eq!(a, fastrand::Rng::with_seed(7));
+    assert_eq!(b, fastrand::Rng::with_seed(7));
+}
+
+#[test]
+fn rand_ecosystem() {
+    let mut rng = fastrand::Rng::with_seed(42);
+
+    let x: u32 = rng.gen_range(10..20);
+    assert!((10..20).contains(&x));
+    assert!(rng.gen_ratio(1, 1));
+
+    let mut v: Vec<i32> = (0..10).collect();
+    v.shuffle(&mut rng);
+    v.sort_unstable();
+    assert_eq!(v, (0..10).collect::<Vec<_>>());
+}
@@ -0,0 +1,39 @@
+#![cfg(feature = "serde")]
+
+#[test]
+fn round_trip() {
+    let mut rng = fastrand::Rng::with_seed(0x1234_5678);
+    for _ in 0..5 {
+        rng.u64(..);
+    }
+
+    let json = serde_json::to_string(&rng).unwrap();
+    assert_eq!(json, rng.get_seed().to_string());
+
+    let mut restored: fastrand::Rng = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored, rng);
+    for _ in 0..10 {
+        assert_eq!(restored.u64(..), rng.u64(..));
+    }
+}
+
+#[test]
+fn round_trip_after_normal() {
+    let mut rng = fastrand::Rng::with_seed(9);
+    rng.normal(0.0, 1.0);
+
+    // The second value of the pending Box-Muller pair is not serialized.
+    let json = serde_json::to_string(&rng).unwrap();
+    let mut restored: fastrand::Rng = serde_json::from_str(&json).unwrap();
+    assert_ne!(restored, rng);
+    assert_eq!(restored.get_seed(), rng.get_seed());
+    assert_eq!(
+        restored.normal(0.0, 1.0),
+        fastrand::Rng::with_seed(rng.get_seed()).normal(0.0, 1.0)
+    );
+    assert_eq!(restored.u64(..), {
+        let mut fresh = fastrand::Rng::with_seed(rng.get_seed());
+        fresh.normal(0.0, 1.0);
+        fresh.u64(..)
+    });
+}
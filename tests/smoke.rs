@@ -1,3 +1,5 @@
+use std::ops::Bound;
+
 #[cfg(all(target_family = "wasm", not(target_os = "wasi")))]
 use wasm_bindgen_test::wasm_bindgen_test as test;
 
@@ -46,6 +48,160 @@ fn u32() {
     }
 }
 
+#[test]
+fn range_or_default() {
+    let mut r = fastrand::Rng::with_seed(5);
+    #[allow(clippy::reversed_empty_ranges)]
+    for _ in 0..100 {
+        assert_eq!(r.range_or(5u32..5, 42), 42);
+        assert_eq!(r.range_or(7u32..3, 42), 42);
+        assert_eq!(r.range_or(..0u32, 42), 42);
+        assert_eq!(r.range_or(u8::MAX.., 1), u8::MAX);
+        assert_eq!(
+            r.range_or((Bound::Excluded(i64::MAX), Bound::Unbounded), -1),
+            -1
+        );
+        assert_eq!(r.range_or(3usize..=2, 9), 9);
+        assert_eq!(r.range_or(1.0..1.0, 0.5), 0.5);
+
+        let x = r.range_or(10u32..20, 0);
+        assert!((10..20).contains(&x));
+        let x = r.range_or::<i8>(.., 0);
+        assert!((i8::MIN..=i8::MAX).contains(&x));
+    }
+
+    // Valid ranges produce the same values as the panicking methods.
+    let mut a = fastrand::Rng::with_seed(6);
+    let mut b = fastrand::Rng::with_seed(6);
+    for _ in 0..100 {
+        assert_eq!(a.range_or(3u64..1000, 0), b.u64(3..1000));
+        assert_eq!(a.range_or(-5i128..=5, 0), b.i128(-5..=5));
+    }
+}
+
+#[test]
+fn try_range() {
+    let mut r = fastrand::Rng::with_seed(7);
+    #[allow(clippy::reversed_empty_ranges)]
+    for _ in 0..100 {
+        assert_eq!(r.try_range(5u32..5), None);
+        assert_eq!(r.try_range(7u32..3), None);
+        assert_eq!(r.try_range(..0u8), None);
+        assert_eq!(r.try_range(..i16::MIN), None);
+        assert_eq!(r.try_range(3usize..=2), None);
+        assert_eq!(r.try_range(1.0f64..1.0), None);
+        assert_eq!(r.try_range(f32::NAN..1.0), None);
+        assert_eq!(r.try_range(0.0..=f64::INFINITY), None);
+        assert_eq!(r.try_char('b'..'a'), None);
+        assert_eq!(r.try_char(..'\0'), None);
+        assert_eq!(
+            r.try_char((Bound::Excluded(char::MAX), Bound::Unbounded)),
+            None
+        );
+
+        assert_eq!(r.try_range(u8::MAX..), Some(u8::MAX));
+        assert!(r.try_range::<i64>(..).is_some());
+        assert_eq!(r.try_range(2.5f32..=2.5), Some(2.5));
+        let c = r.try_char('\u{d7ff}'..='\u{e000}').unwrap();
+        assert!(c == '\u{d7ff}' || c == '\u{e000}');
+    }
+
+    // Valid ranges produce the same values as the panicking methods.
+    let mut a = fastrand::Rng::with_seed(8);
+    let mut b = fastrand::Rng::with_seed(8);
+    for _ in 0..100 {
+        assert_eq!(a.try_range(10u32..20), Some(b.u32(10..20)));
+        assert_eq!(a.try_range(-5i128..=5), Some(b.i128(-5..=5)));
+        assert_eq!(a.try_range::<isize>(..), Some(b.isize(..)));
+        assert_eq!(
+            a.try_range(0.0..f64::INFINITY),
+            Some(b.f64_range(0.0..f64::INFINITY))
+        );
+        assert_eq!(a.try_char('a'..='z'), Some(b.char('a'..='z')));
+    }
+}
+
+#[test]
+fn parity() {
+    let mut r = fastrand::Rng::with_seed(9);
+    let mut seen = [false; 11];
+    for _ in 0..1000 {
+        let x = r.even_u32(3..=13);
+        assert!(x % 2 == 0 && (3..=13).contains(&x));
+        seen[x as usize - 3] = true;
+
+        let x = r.odd_u32(3..=13);
+        assert!(x % 2 == 1 && (3..=13).contains(&x));
+        seen[x as usize - 3] = true;
+
+        let x = r.odd_i64(-7..-2);
+        assert!(x % 2 == -1 && (-7..-2).contains(&x));
+        let x = r.even_i32(..);
+        assert_eq!(x % 2, 0);
+        let x = r.odd_u64(u64::MAX - 1..);
+        assert_eq!(x, u64::MAX);
+        let x = r.even_usize(..=1);
+        assert_eq!(x, 0);
+        let x = r.even_i64(i64::MIN..=i64::MIN + 1);
+        assert_eq!(x, i64::MIN);
+    }
+    assert!(seen.iter().all(|&x| x));
+
+    // Both values of the parity are reachable at the extremes of the type.
+    let mut ends = [false; 4];
+    for _ in 0..1000 {
+        match r.odd_i32(i32::MAX - 3..) {
+            x if x == i32::MAX - 2 => ends[0] = true,
+            i32::MAX => ends[1] = true,
+            x => panic!("{}", x),
+        }
+        match r.even_i32(..=i32::MIN + 3) {
+            i32::MIN => ends[2] = true,
+            x if x == i32::MIN + 2 => ends[3] = true,
+            x => panic!("{}", x),
+        }
+    }
+    assert_eq!(ends, [true; 4]);
+}
+
+#[test]
+#[should_panic]
+fn parity_empty() {
+    fastrand::Rng::new().even_u32(3..4);
+}
+
+#[test]
+fn multiple_of() {
+    let mut r = fastrand::Rng::with_seed(10);
+    let mut seen = [false; 4];
+    for _ in 0..1000 {
+        let x = r.multiple_of(7, 5..30);
+        assert!(x % 7 == 0 && (5..30).contains(&x));
+        seen[x as usize / 7 - 1] = true;
+
+        assert_eq!(r.multiple_of(3, ..3), 0);
+        assert_eq!(r.multiple_of(1, 42..=42), 42);
+
+        let x = fastrand::multiple_of(1 << 40, ..);
+        assert_eq!(x % (1 << 40), 0);
+        let x = r.multiple_of(u64::MAX, 1..);
+        assert_eq!(x, u64::MAX);
+    }
+    assert!(seen.iter().all(|&x| x));
+}
+
+#[test]
+#[should_panic]
+fn multiple_of_empty() {
+    fastrand::Rng::new().multiple_of(10, 11..20);
+}
+
+#[test]
+#[should_panic]
+fn multiple_of_zero() {
+    fastrand::Rng::new().multiple_of(0, ..);
+}
+
 #[test]
 fn u64() {
     for n in 1u64..10_000 {
@@ -141,3 +297,1171 @@ fn choice() {
         while r.choice(&items).unwrap() != item {}
     }
 }
+
+#[test]
+fn point_in_box() {
+    let mut r = fastrand::Rng::new();
+    let mins = [-1.0, 0.0, 5.0, f64::MIN];
+    let maxs = [1.0, 0.0, 6.0, f64::MAX];
+    let mut point = [0.0; 4];
+
+    for _ in 0..1000 {
+        r.point_in_box(&mins, &maxs, &mut point);
+        assert!(point[0] >= -1.0 && point[0] < 1.0);
+        assert_eq!(point[1], 0.0);
+        assert!(point[2] >= 5.0 && point[2] < 6.0);
+        assert!(point[3].is_finite());
+    }
+}
+
+#[test]
+#[should_panic]
+fn point_in_box_infinite_max() {
+    fastrand::Rng::new().point_in_box(&[0.0], &[f64::INFINITY], &mut [0.0]);
+}
+
+#[test]
+#[should_panic]
+fn point_in_box_infinite_point() {
+    fastrand::Rng::new().point_in_box(&[f64::INFINITY], &[f64::INFINITY], &mut [0.0]);
+}
+
+#[test]
+fn draw_weighted() {
+    let mut r = fastrand::Rng::with_seed(7);
+
+    let mut items = vec![1, 2, 3, 4];
+    let mut weights = vec![1.0, 0.0, 2.0, 3.0];
+    let mut drawn = Vec::new();
+    while let Some(x) = r.draw_weighted(&mut items, &mut weights) {
+        drawn.push(x);
+    }
+    drawn.sort_unstable();
+    assert_eq!(drawn, [1, 3, 4]);
+    assert_eq!(items, [2]);
+    assert_eq!(weights, [0.0]);
+
+    let mut counts = [0; 2];
+    for _ in 0..10_000 {
+        let mut items = vec![0, 1];
+        let mut weights = vec![1.0, 3.0];
+        counts[r.draw_weighted(&mut items, &mut weights).unwrap()] += 1;
+    }
+    assert!(counts[1] > 2 * counts[0]);
+}
+
+#[test]
+fn jitter_duration() {
+    use std::time::Duration;
+
+    let mut r = fastrand::Rng::new();
+    let base = Duration::from_millis(1000);
+
+    for _ in 0..1000 {
+        let d = r.jitter_duration(base, 0.25);
+        assert!(d >= Duration::from_millis(750) && d <= Duration::from_millis(1250));
+    }
+    assert_eq!(r.jitter_duration(base, 0.0), base);
+
+    let max = Duration::new(u64::MAX, 999_999_999);
+    assert!(r.jitter_duration(max, 1.0) <= max);
+}
+
+#[test]
+fn backoff_jitter() {
+    use std::time::Duration;
+
+    let mut r = fastrand::Rng::with_seed(23);
+    let base = Duration::from_millis(10);
+    let cap = Duration::from_secs(1);
+
+    let mut means = Vec::new();
+    for attempt in 0..12 {
+        let ceiling = (base * 2u32.pow(attempt)).min(cap);
+        let mut total = Duration::from_secs(0);
+        for _ in 0..1000 {
+            let d = r.backoff_jitter(base, cap, attempt);
+            assert!(d <= ceiling && d <= cap);
+            total += d;
+        }
+        means.push(total / 1000);
+    }
+
+    // The delays grow with each attempt until they reach the cap.
+    assert!(means.windows(2).take(6).all(|w| w[0] < w[1]));
+    let capped = means[11].as_secs_f64();
+    assert!((capped - 0.5).abs() < 0.05, "{}", capped);
+
+    // Huge attempts saturate instead of overflowing.
+    for &attempt in &[64, 127, 128, u32::MAX] {
+        assert!(r.backoff_jitter(base, cap, attempt) <= cap);
+        assert_eq!(
+            r.backoff_jitter(Duration::from_secs(0), cap, attempt),
+            Duration::from_secs(0)
+        );
+    }
+    let max = Duration::new(u64::MAX, 999_999_999);
+    assert!(r.backoff_jitter(max, max, 3) <= max);
+    assert_eq!(
+        r.backoff_jitter(base, Duration::from_secs(0), 5),
+        Duration::from_secs(0)
+    );
+}
+
+#[test]
+fn rewind() {
+    let mut r = fastrand::Rng::with_seed(42);
+    let start = r.get_seed();
+    let outputs: Vec<u64> = (0..100).map(|_| r.u64(..)).collect();
+
+    r.rewind(100);
+    assert_eq!(r.get_seed(), start);
+    for &x in &outputs {
+        assert_eq!(r.u64(..), x);
+    }
+
+    r.rewind(1);
+    assert_eq!(r.u64(..), outputs[99]);
+}
+
+#[test]
+fn sorted_unique_u64() {
+    let mut r = fastrand::Rng::new();
+
+    for &(count, low, high) in &[(0, 5, 10), (6, 5, 10), (3, 5, 10), (50, 0, 1_000_000)] {
+        let keys = r.sorted_unique_u64(count, low..=high);
+        assert_eq!(keys.len(), count);
+        assert!(keys.windows(2).all(|w| w[0] < w[1]));
+        assert!(keys.iter().all(|&k| k >= low && k <= high));
+    }
+
+    assert_eq!(r.sorted_unique_u64(4, 3..7), [3, 4, 5, 6]);
+    assert_eq!(r.sorted_unique_u64(10, ..).len(), 10);
+    assert_eq!(
+        r.sorted_unique_u64(2, u64::MAX - 1..),
+        [u64::MAX - 1, u64::MAX]
+    );
+}
+
+#[test]
+fn cooldown_picker() {
+    let mut r = fastrand::Rng::new();
+
+    let mut picker = fastrand::CooldownPicker::new((0..5).collect(), 3);
+    let mut history = Vec::new();
+    let mut seen = [false; 5];
+    for _ in 0..1000 {
+        let x = *picker.pick(&mut r);
+        assert!(!history.iter().rev().take(3).any(|&y| y == x));
+        history.push(x);
+        seen[x] = true;
+    }
+    assert!(seen.iter().all(|&s| s));
+
+    // The window is relaxed so that picks cycle through every item.
+    let mut picker = fastrand::CooldownPicker::new(vec!['a', 'b', 'c'], 10);
+    assert_eq!(picker.window(), 2);
+    let mut round: Vec<char> = (0..3).map(|_| *picker.pick(&mut r)).collect();
+    round.sort_unstable();
+    assert_eq!(round, ['a', 'b', 'c']);
+}
+
+#[test]
+fn mixture() {
+    use fastrand::{Mixture, Rng};
+
+    let mixture = Mixture::new(vec![
+        (1.0, Box::new(|_: &mut Rng| 0.0)),
+        (3.0, Box::new(|r: &mut Rng| 1.0 + r.f64())),
+    ]);
+    assert_eq!(mixture.weights(), [1.0, 3.0]);
+
+    let mut r = Rng::with_seed(3);
+    let mut low = 0;
+    for _ in 0..10_000 {
+        let x = mixture.sample(&mut r);
+        if x == 0.0 {
+            low += 1;
+        } else {
+            assert!((1.0..2.0).contains(&x));
+        }
+    }
+    assert!((2000..3000).contains(&low));
+}
+
+#[test]
+fn lat_lon() {
+    let mut r = fastrand::Rng::with_seed(5);
+    let n = 100_000;
+    let mut polar = 0;
+
+    for _ in 0..n {
+        let (lat, lon) = r.lat_lon();
+        assert!((-90.0..90.0).contains(&lat));
+        assert!((-180.0..180.0).contains(&lon));
+        if lat.abs() > 60.0 {
+            polar += 1;
+        }
+    }
+
+    // The caps above 60 degrees cover 1 - sin(60) of the sphere's surface.
+    let expected = (1.0 - 60f64.to_radians().sin()) * n as f64;
+    assert!((polar as f64 - expected).abs() < 0.05 * expected);
+}
+
+#[test]
+#[allow(deprecated)]
+fn choose_weighted_index() {
+    let mut r = fastrand::Rng::with_seed(11);
+
+    assert_eq!(r.choose_weighted_index(&[]), None);
+    assert_eq!(r.choose_weighted_index(&[0.0, 0.0]), None);
+    assert_eq!(r.choose_weighted_index(&[1.0, -1.0]), None);
+    assert_eq!(r.choose_weighted_index_f32(&[1.0, f32::NAN]), None);
+    assert_eq!(r.choose_weighted_index_f32(&[0.0, 2.5]), Some(1));
+
+    let mut counts = [0; 3];
+    for _ in 0..10_000 {
+        counts[r.choose_weighted_index_f32(&[1.0, 2.0, 7.0]).unwrap()] += 1;
+    }
+    assert!((800..1200).contains(&counts[0]));
+    assert!((1800..2200).contains(&counts[1]));
+    assert!((6700..7300).contains(&counts[2]));
+}
+
+#[test]
+fn weighted_index() {
+    let mut r = fastrand::Rng::with_seed(12);
+    assert_eq!(r.weighted_index(&[]), None);
+    assert_eq!(r.weighted_index(&[0.0, -1.0]), None);
+    assert_eq!(r.weighted_index_f32(&[0.0, 3.0]), Some(1));
+
+    let mut counts = [0; 4];
+    for _ in 0..10_000 {
+        counts[fastrand::weighted_index(&[0.5, 0.0, 1.5, 2.0]).unwrap()] += 1;
+    }
+    assert!((1050..1450).contains(&counts[0]));
+    assert_eq!(counts[1], 0);
+    assert!((3450..4050).contains(&counts[2]));
+    assert!((4700..5300).contains(&counts[3]));
+}
+
+#[test]
+fn semver() {
+    fn is_numeric(id: &str) -> bool {
+        id.bytes().all(|b| b.is_ascii_digit()) && (id == "0" || !id.starts_with('0'))
+    }
+    fn is_alphanumeric(id: &str) -> bool {
+        id.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-')
+    }
+
+    let mut r = fastrand::Rng::new();
+    for i in 0..1000 {
+        let s = match i % 3 {
+            0 => r.semver_with(true, true),
+            1 => r.semver(),
+            _ => fastrand::semver(),
+        };
+
+        let (rest, build) = match s.find('+') {
+            Some(i) => (&s[..i], Some(&s[i + 1..])),
+            None => (&s[..], None),
+        };
+        let (core, pre) = match rest.find('-') {
+            Some(i) => (&rest[..i], Some(&rest[i + 1..])),
+            None => (rest, None),
+        };
+
+        let parts: Vec<&str> = core.split('.').collect();
+        assert_eq!(parts.len(), 3, "{}", s);
+        assert!(parts.iter().all(|p| is_numeric(p)), "{}", s);
+        for id in pre.into_iter().flat_map(|p| p.split('.')) {
+            assert!(!id.is_empty() && (is_numeric(id) || !id.bytes().all(|b| b.is_ascii_digit())));
+            assert!(is_alphanumeric(id), "{}", s);
+        }
+        for id in build.into_iter().flat_map(|b| b.split('.')) {
+            assert!(!id.is_empty() && is_alphanumeric(id), "{}", s);
+        }
+        if i % 3 == 0 {
+            assert!(pre.is_some() && build.is_some());
+        }
+    }
+}
+
+#[test]
+fn weighted_samples() {
+    let mut r = fastrand::Rng::with_seed(17);
+    let mut counts = [0; 4];
+
+    for i in r.weighted_samples(&[1, 1, 4, 10]).take(10_000) {
+        counts[i] += 1;
+    }
+    assert_eq!(counts[1], 0);
+    assert!((850..1150).contains(&counts[0]));
+    assert!((2800..3200).contains(&counts[2]));
+    assert!((5800..6200).contains(&counts[3]));
+}
+
+#[test]
+fn wyrand() {
+    let mut state = 0x4d59_5df4_d0f3_3173;
+    let mut r = fastrand::Rng::with_seed(state);
+
+    for _ in 0..100 {
+        assert_eq!(fastrand::wyrand(&mut state), r.u64(..));
+        assert_eq!(state, r.get_seed());
+    }
+}
+
+#[test]
+fn weighted_choice() {
+    let items = ['a', 'b', 'c'];
+    let mut r = fastrand::Rng::with_seed(9);
+
+    assert_eq!(r.weighted_choice::<char>(&[], &[]), None);
+    assert_eq!(r.weighted_choice(&items, &[1.0, 1.0]), None);
+    assert_eq!(r.weighted_choice(&items, &[1.0, f64::NAN, 1.0]), None);
+    assert_eq!(r.weighted_choice(&items, &[1.0, -0.5, 1.0]), None);
+
+    let weights = [5.0, 3.0, 2.0];
+    let mut counts = [0; 3];
+    for _ in 0..10_000 {
+        let c = r.weighted_choice(&items, &weights).unwrap();
+        counts[(*c as u8 - b'a') as usize] += 1;
+    }
+    assert!((4700..5300).contains(&counts[0]));
+    assert!((2700..3300).contains(&counts[1]));
+    assert!((1700..2300).contains(&counts[2]));
+
+    for _ in 0..100 {
+        assert_eq!(
+            fastrand::weighted_choice(&items, &[0.0, 1.0, 0.0]),
+            Some(&'b')
+        );
+    }
+}
+
+#[test]
+fn bool_with_probability() {
+    let mut r = fastrand::Rng::with_seed(4);
+
+    let hits = (0..100_000)
+        .filter(|_| r.bool_with_probability(0.25))
+        .count();
+    assert!((24_000..26_000).contains(&hits));
+
+    for _ in 0..1000 {
+        assert!(!r.bool_with_probability(0.0));
+        assert!(!r.bool_with_probability(-1.0));
+        assert!(!r.bool_with_probability(f64::NAN));
+        assert!(r.bool_with_probability(1.0));
+        assert!(fastrand::bool_with_probability(2.0));
+    }
+}
+
+#[test]
+fn fill_u32_u64() {
+    let mut r = fastrand::Rng::with_seed(3);
+    let mut a = [0u32; 17];
+    let mut b = [0u32; 17];
+    r.fill_u32(&mut a);
+    r.fill_u32(&mut b);
+    assert_ne!(a, b);
+
+    let mut a = [0u64; 17];
+    let mut b = fastrand::Rng::with_seed(3);
+    r.seed(3);
+    r.fill_u64(&mut a);
+    for &x in &a {
+        assert_eq!(x, b.u64(..));
+    }
+}
+
+#[test]
+fn fill_bools() {
+    let mut r = fastrand::Rng::with_seed(4);
+    let mut a = [false; 200];
+    let mut b = [false; 200];
+    r.fill_bools(&mut a);
+    r.fill_bools(&mut b);
+    assert_ne!(a[..], b[..]);
+
+    let trues = a.iter().chain(&b).filter(|&&x| x).count();
+    assert!((150..250).contains(&trues));
+
+    // Each block of output fills 64 elements, low bit first.
+    let mut a = [false; 70];
+    r.seed(4);
+    r.fill_bools(&mut a);
+    let mut raw = fastrand::Rng::with_seed(4);
+    let (first, second) = (raw.next_u64(), raw.next_u64());
+    for (i, &x) in a.iter().enumerate() {
+        let bit = if i < 64 {
+            first >> i
+        } else {
+            second >> (i - 64)
+        };
+        assert_eq!(x, bit & 1 == 1);
+    }
+}
+
+#[test]
+fn fill_array() {
+    let mut r = fastrand::Rng::with_seed(8);
+    let mut expected = [0u8; 13];
+    r.fill(&mut expected);
+
+    r.seed(8);
+    assert_eq!(r.fill_array::<13>(), expected);
+
+    let seed = r.get_seed();
+    let empty: [u8; 0] = r.fill_array();
+    assert_eq!(empty, []);
+    assert_eq!(r.get_seed(), seed);
+
+    let a: [u8; 32] = fastrand::fill_array();
+    let b: [u8; 32] = fastrand::fill_array();
+    assert_ne!(a, b);
+}
+
+#[test]
+fn nonzero() {
+    let mut r = fastrand::Rng::new();
+    for _ in 0..10_000 {
+        assert_ne!(r.nonzero_u8().get(), 0);
+        assert_ne!(r.nonzero_u16().get(), 0);
+        assert_ne!(r.nonzero_u32().get(), 0);
+        assert_ne!(r.nonzero_u64().get(), 0);
+        assert_ne!(r.nonzero_u128().get(), 0);
+        assert_ne!(r.nonzero_usize().get(), 0);
+    }
+
+    for x in 1..=u8::MAX {
+        while r.nonzero_u8().get() != x {}
+    }
+}
+
+#[test]
+fn hex_string() {
+    let mut r = fastrand::Rng::new();
+
+    let seed = r.get_seed();
+    assert_eq!(r.hex_string(0), "");
+    assert_eq!(r.get_seed(), seed);
+
+    let mut seen = [false; 16];
+    for len in 0..100 {
+        let s = r.hex_string(len);
+        assert_eq!(s.len(), len);
+        for c in s.chars() {
+            seen[c.to_digit(16).unwrap() as usize] = true;
+            assert!(!c.is_ascii_uppercase());
+        }
+    }
+    assert_eq!(seen, [true; 16]);
+
+    assert_ne!(fastrand::hex_string(16), fastrand::hex_string(16));
+}
+
+#[test]
+fn to_from_bytes() {
+    let mut r = fastrand::Rng::with_seed(0x0102_0304_0506_0708);
+    assert_eq!(r.to_bytes(), [8, 7, 6, 5, 4, 3, 2, 1]);
+
+    r.u64(..);
+    let mut restored = fastrand::Rng::from_bytes(r.to_bytes());
+    for _ in 0..10 {
+        assert_eq!(restored.u64(..), r.u64(..));
+    }
+}
+
+#[test]
+fn display_from_str() {
+    let mut r = fastrand::Rng::with_seed(12345);
+    assert_eq!(r.to_string(), "12345");
+
+    r.u64(..);
+    let mut parsed: fastrand::Rng = r.to_string().parse().unwrap();
+    assert_eq!(parsed, r);
+    assert_eq!(parsed.u64(..), r.u64(..));
+
+    assert_eq!("0xff".parse::<fastrand::Rng>().unwrap().get_seed(), 255);
+    assert!("".parse::<fastrand::Rng>().is_err());
+    assert!("-1".parse::<fastrand::Rng>().is_err());
+    assert!("0xzz".parse::<fastrand::Rng>().is_err());
+    assert!("18446744073709551616".parse::<fastrand::Rng>().is_err());
+}
+
+#[test]
+fn jump() {
+    let mut a = fastrand::Rng::with_seed(99);
+    let mut b = a.clone();
+
+    for k in &[0, 1, 2, 10, 1000] {
+        for _ in 0..*k {
+            a.u64(..);
+        }
+        b.jump(*k);
+        assert_eq!(a, b);
+    }
+
+    let start = a.get_seed();
+    a.jump(u64::MAX);
+    a.rewind(u64::MAX);
+    assert_eq!(a.get_seed(), start);
+}
+
+#[test]
+fn split() {
+    let mut a = fastrand::Rng::with_seed(1).split();
+    let mut b = fastrand::Rng::with_seed(2).split();
+
+    let n = 1000;
+    let xs: Vec<f64> = (0..n).map(|_| a.f64()).collect();
+    let ys: Vec<f64> = (0..n).map(|_| b.f64()).collect();
+
+    let mean = |v: &[f64]| v.iter().sum::<f64>() / n as f64;
+    let (mx, my) = (mean(&xs), mean(&ys));
+    let cov: f64 = xs.iter().zip(&ys).map(|(x, y)| (x - mx) * (y - my)).sum();
+    let var_x: f64 = xs.iter().map(|x| (x - mx) * (x - mx)).sum();
+    let var_y: f64 = ys.iter().map(|y| (y - my) * (y - my)).sum();
+    let correlation = cov / (var_x * var_y).sqrt();
+    assert!(correlation.abs() < 0.1, "correlation = {}", correlation);
+
+    let mut parent = fastrand::Rng::with_seed(1);
+    assert_ne!(parent.split(), parent.split());
+}
+
+#[test]
+fn fork_n() {
+    let mut parent = fastrand::Rng::with_seed(2);
+    let mut children = parent.fork_n(1000);
+    assert_eq!(children.len(), 1000);
+
+    let firsts: std::collections::HashSet<u64> =
+        children.iter_mut().map(|child| child.u64(..)).collect();
+    assert_eq!(firsts.len(), 1000);
+
+    // Without a repeated output, this matches forking in a loop.
+    let mut parent = fastrand::Rng::with_seed(3);
+    let mut expected = parent.clone();
+    for child in parent.fork_n(10) {
+        assert_eq!(child, expected.fork());
+    }
+    assert_eq!(parent, expected);
+    assert!(parent.fork_n(0).is_empty());
+}
+
+#[test]
+fn choose_multiple_unknown_length() {
+    let mut r = fastrand::Rng::with_seed(6);
+
+    // A filtered iterator has no useful size hint.
+    let short = r.choose_multiple((0..10).filter(|x| x % 2 == 0), 8);
+    assert_eq!(short.len(), 5);
+
+    let mut counts = [0; 10];
+    for _ in 0..10_000 {
+        let chosen = r.choose_multiple((0..1000).filter(|x| x % 100 == 0), 3);
+        assert_eq!(chosen.len(), 3);
+        for x in chosen {
+            counts[x / 100] += 1;
+        }
+    }
+    for &count in &counts {
+        assert!((2700..3300).contains(&count), "{:?}", counts);
+    }
+}
+
+#[test]
+fn iter_with() {
+    let mut a = fastrand::Rng::with_seed(5);
+    let mut b = fastrand::Rng::with_seed(5);
+
+    let xs: Vec<u64> = a.iter_u64().take(10).collect();
+    let ys: Vec<u64> = b.iter_with(|r| r.u64(..)).take(10).collect();
+    assert_eq!(xs, ys);
+    assert_eq!(a, b);
+
+    assert!(a.iter_f64().take(100).all(|x| (0.0..1.0).contains(&x)));
+    assert!(a.iter_bool().take(100).any(|x| x));
+    assert_eq!(a.iter_u32().take(5).count(), 5);
+}
+
+#[test]
+fn shuffle_uniform() {
+    use std::collections::HashMap;
+
+    let mut r = fastrand::Rng::with_seed(21);
+    let mut counts = HashMap::new();
+    let n = 240_000;
+    for _ in 0..n {
+        let mut v = [0, 1, 2, 3];
+        r.shuffle(&mut v);
+        *counts.entry(v).or_insert(0u32) += 1;
+    }
+
+    // All 24 permutations appear with roughly equal frequency.
+    assert_eq!(counts.len(), 24);
+    let expected = n as f64 / 24.0;
+    let chi_squared: f64 = counts
+        .values()
+        .map(|&x| (x as f64 - expected).powi(2) / expected)
+        .sum();
+    // The 99.9th percentile of the chi-squared distribution with 23 degrees of freedom.
+    assert!(chi_squared < 49.7, "chi-squared = {}", chi_squared);
+}
+
+#[test]
+fn choose_multiple_iter() {
+    let items: Vec<u32> = (0..50).collect();
+
+    for &amount in &[0, 1, 7, 49, 50, 80] {
+        let mut r = fastrand::Rng::with_seed(amount as u64);
+        let lazy: Vec<u32> = r.choose_multiple_iter(&items, amount).copied().collect();
+
+        // The same seed leaves the same items at the front of a partially shuffled copy.
+        let mut copy = items.clone();
+        let mut r = fastrand::Rng::with_seed(amount as u64);
+        let (front, _) = r.partial_shuffle(&mut copy, amount);
+        assert_eq!(lazy, front);
+
+        // The items are distinct, like those collected by `choose_multiple`.
+        let set: std::collections::HashSet<u32> = lazy.iter().copied().collect();
+        let eager = r.choose_multiple(&items, amount);
+        assert_eq!(set.len(), eager.len());
+    }
+
+    // Items can be consumed one at a time.
+    let mut r = fastrand::Rng::with_seed(1);
+    let mut iter = r.choose_multiple_iter(&items, 3);
+    let first = *iter.next().unwrap();
+    assert!(iter.all(|&x| x != first));
+}
+
+#[test]
+fn partial_shuffle() {
+    let mut r = fastrand::Rng::with_seed(10);
+
+    let mut v: Vec<u32> = (0..10).collect();
+    let (sample, rest) = r.partial_shuffle(&mut v, 4);
+    assert_eq!((sample.len(), rest.len()), (4, 6));
+    v.sort_unstable();
+    assert_eq!(v, (0..10).collect::<Vec<_>>());
+
+    let (sample, rest) = r.partial_shuffle(&mut v, 20);
+    assert_eq!((sample.len(), rest.len()), (10, 0));
+
+    // Every element is equally likely to end up at the front of the sample.
+    let mut counts = [0; 5];
+    for _ in 0..10_000 {
+        let mut v = [0, 1, 2, 3, 4];
+        let (sample, _) = r.partial_shuffle(&mut v, 2);
+        counts[sample[0]] += 1;
+        assert_ne!(sample[0], sample[1]);
+    }
+    for &count in &counts {
+        assert!((1800..2200).contains(&count), "{:?}", counts);
+    }
+}
+
+#[test]
+fn sample_indices() {
+    let mut r = fastrand::Rng::new();
+    let mut out = Vec::new();
+
+    for &(len, amount) in &[(0, 0), (10, 10), (10, 3), (10_000, 50), (100, 99)] {
+        r.sample_indices(len, amount, &mut out);
+        assert_eq!(out.len(), amount);
+        assert!(out.iter().all(|&i| i < len));
+
+        let mut sorted = out.clone();
+        sorted.sort_unstable();
+        sorted.dedup();
+        assert_eq!(sorted.len(), amount);
+    }
+}
+
+#[test]
+fn slice_ext() {
+    use fastrand::SliceExt;
+
+    let mut r = fastrand::Rng::new();
+    let mut v: Vec<u32> = (0..20).collect();
+
+    v.shuffle(&mut r);
+    let x = *v.choose(&mut r).unwrap();
+    assert!(x < 20);
+
+    *v.choose_mut(&mut r).unwrap() = 100;
+    assert_eq!(v.iter().filter(|&&x| x == 100).count(), 1);
+
+    let (sample, rest) = v.partial_shuffle(&mut r, 5);
+    assert_eq!((sample.len(), rest.len()), (5, 15));
+
+    let empty: &mut [u32] = &mut [];
+    assert_eq!(empty.choose(&mut r), None);
+    assert_eq!(empty.choose_mut(&mut r), None);
+}
+
+#[test]
+fn choose_mut() {
+    let mut r = fastrand::Rng::with_seed(3);
+    let mut empty: [u8; 0] = [];
+    assert_eq!(r.choose_mut(&mut empty), None);
+
+    let mut v = [0u32; 10];
+    for _ in 0..100 {
+        *r.choose_mut(&mut v).unwrap() += 1;
+    }
+    assert_eq!(v.iter().sum::<u32>(), 100);
+    assert!(v.iter().all(|&x| x > 0));
+}
+
+#[test]
+fn choose_with_index() {
+    let mut r = fastrand::Rng::with_seed(4);
+    let empty: [u8; 0] = [];
+    assert_eq!(r.choose_with_index(&empty), None);
+
+    let v: Vec<u32> = (100..110).collect();
+    let mut seen = [false; 10];
+    for _ in 0..1000 {
+        let (i, x) = r.choose_with_index(&v).unwrap();
+        assert!(std::ptr::eq(x, &v[i]));
+        seen[i] = true;
+    }
+    assert!(seen.iter().all(|&x| x));
+
+    let (i, x) = fastrand::choose_with_index(&v).unwrap();
+    assert_eq!(*x, 100 + i as u32);
+}
+
+#[test]
+fn ratio() {
+    let mut r = fastrand::Rng::with_seed(11);
+    let hits = (0..70_000).filter(|_| r.ratio(3, 7)).count();
+    assert!((29_000..31_000).contains(&hits));
+
+    for _ in 0..100 {
+        assert!(!r.ratio(0, 5));
+        assert!(r.ratio(5, 5));
+        assert!(r.ratio(9, 5));
+    }
+    let _ = fastrand::ratio(1, 2);
+}
+
+#[test]
+#[should_panic]
+fn ratio_zero_denominator() {
+    fastrand::Rng::new().ratio(0, 0);
+}
+
+#[test]
+fn colors() {
+    let mut r = fastrand::Rng::with_seed(13);
+    let mut seen = [[false; 256]; 4];
+    for _ in 0..10_000 {
+        let (red, green, blue, alpha) = r.rgba();
+        for (channel, &value) in [red, green, blue, alpha].iter().enumerate() {
+            seen[channel][value as usize] = true;
+        }
+
+        let (h, s, v) = r.hsv();
+        assert!((0.0..360.0).contains(&h));
+        assert!((0.0..1.0).contains(&s));
+        assert!((0.0..1.0).contains(&v));
+    }
+    assert!(seen.iter().all(|channel| channel.iter().all(|&x| x)));
+
+    let (red, green, blue, _) = fastrand::Rng::with_seed(14).rgba();
+    assert_eq!(fastrand::Rng::with_seed(14).rgb(), (red, green, blue));
+    let (h, _, _) = fastrand::hsv();
+    assert!(h < 360.0);
+}
+
+#[test]
+fn sign() {
+    let mut r = fastrand::Rng::with_seed(12);
+    let mut sum_f = 0.0;
+    let mut sum_i = 0;
+    for _ in 0..10_000 {
+        let f = r.sign_f64();
+        assert!(f == 1.0 || f == -1.0);
+        sum_f += f;
+
+        let i = fastrand::sign_i32();
+        assert!(i == 1 || i == -1);
+        sum_i += i;
+    }
+    assert!(sum_f.abs() < 500.0);
+    assert!(sum_i.abs() < 500);
+}
+
+#[test]
+#[cfg(not(all(target_family = "wasm", target_os = "unknown", not(feature = "js"))))]
+fn reseed() {
+    fastrand::seed(7);
+    let a: Vec<u64> = (0..4).map(|_| fastrand::u64(..)).collect();
+
+    fastrand::seed(7);
+    fastrand::reseed();
+    let b: Vec<u64> = (0..4).map(|_| fastrand::u64(..)).collect();
+
+    assert_ne!(a, b);
+}
+
+#[test]
+#[cfg(not(all(target_family = "wasm", target_os = "unknown", not(feature = "js"))))]
+fn from_entropy() {
+    let mut a = fastrand::Rng::from_entropy();
+    let mut b = fastrand::Rng::from_entropy();
+    assert_ne!(a, b);
+    assert_ne!(a.u64(..), b.u64(..));
+
+    // Seeding the thread-local generator does not affect it.
+    fastrand::seed(7);
+    let c = fastrand::Rng::from_entropy();
+    fastrand::seed(7);
+    assert_ne!(c, fastrand::Rng::from_entropy());
+}
+
+#[test]
+fn from_build_hasher() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{BuildHasherDefault, Hasher};
+
+    let fixed = BuildHasherDefault::<DefaultHasher>::default();
+    assert_eq!(
+        fastrand::Rng::from_build_hasher(&fixed),
+        fastrand::Rng::with_seed(DefaultHasher::new().finish())
+    );
+
+    let mut a = fastrand::Rng::from_random_state();
+    let mut b = fastrand::Rng::from_random_state();
+    assert_ne!(a, b);
+    let xs: Vec<u64> = (0..4).map(|_| a.u64(..)).collect();
+    let ys: Vec<u64> = (0..4).map(|_| b.u64(..)).collect();
+    assert_ne!(xs, ys);
+}
+
+#[test]
+fn generic_range() {
+    fn check<T>(r: &mut fastrand::Rng, low: T, high: T)
+    where
+        T: fastrand::RandomRange + PartialOrd + Copy + std::fmt::Debug,
+    {
+        for _ in 0..100 {
+            let x = r.range(low..high);
+            assert!(low <= x && x < high, "{:?} not in {:?}..{:?}", x, low, high);
+            let y = r.range(low..=high);
+            assert!(
+                low <= y && y <= high,
+                "{:?} not in {:?}..={:?}",
+                y,
+                low,
+                high
+            );
+        }
+    }
+
+    let mut r = fastrand::Rng::with_seed(12);
+    check(&mut r, 3u8, 9);
+    check(&mut r, -7i32, 2);
+    check(&mut r, 1u64 << 40, 1 << 41);
+    check(&mut r, -5i128, 5);
+    check(&mut r, 0usize, 10);
+
+    let mut a = fastrand::Rng::with_seed(1);
+    let mut b = fastrand::Rng::with_seed(1);
+    assert_eq!(a.range::<u16>(..), b.u16(..));
+    assert_eq!(a.gen_range(10u32..20), b.u32(10..20));
+    assert_eq!(a.gen_range(0.5..1.5), b.f64_range(0.5..1.5));
+    assert_eq!(a.range(-1.0f32..=1.0), b.f32_range(-1.0..=1.0));
+}
+
+#[test]
+fn generic_gen() {
+    fn same<T: fastrand::Random + PartialEq + std::fmt::Debug>(
+        seed: u64,
+        f: fn(&mut fastrand::Rng) -> T,
+    ) {
+        let mut a = fastrand::Rng::with_seed(seed);
+        let mut b = fastrand::Rng::with_seed(seed);
+        for _ in 0..10 {
+            assert_eq!(a.gen::<T>(), f(&mut b));
+        }
+    }
+
+    same(1, |r| r.u8(..));
+    same(2, |r| r.u16(..));
+    same(3, |r| r.u32(..));
+    same(4, |r| r.u64(..));
+    same(5, |r| r.u128(..));
+    same(6, |r| r.usize(..));
+    same(7, |r| r.i8(..));
+    same(8, |r| r.i16(..));
+    same(9, |r| r.i32(..));
+    same(10, |r| r.i64(..));
+    same(11, |r| r.i128(..));
+    same(12, |r| r.isize(..));
+    same(13, |r| r.bool());
+    same(14, |r| r.f32());
+    same(15, |r| r.f64());
+    same(16, |r| r.char(..));
+}
+
+#[test]
+fn const_with_seed() {
+    const RNG: fastrand::Rng = fastrand::Rng::with_seed(42);
+    const SEED: u64 = RNG.get_seed();
+    const STEP: (u64, u64) = fastrand::wyrand_const(SEED);
+
+    let mut rng = RNG;
+    assert_eq!(SEED, 42);
+    assert_eq!(rng.u64(..), STEP.1);
+    assert_eq!(rng.get_seed(), STEP.0);
+}
+
+#[test]
+fn seed_bytes() {
+    use fastrand::Rng;
+
+    let stream = |mut r: Rng| (0..8).map(|_| r.u64(..)).collect::<Vec<_>>();
+
+    let seed = b"a seed that is longer than eight bytes";
+    let copy = seed.to_vec();
+    assert_eq!(
+        stream(Rng::from_seed_bytes(seed)),
+        stream(Rng::from_seed_bytes(&copy))
+    );
+    assert_ne!(
+        stream(Rng::from_seed_bytes(b"abc")),
+        stream(Rng::from_seed_bytes(b"abd"))
+    );
+    assert_ne!(Rng::from_seed_bytes(b""), Rng::from_seed_bytes(&[0]));
+    assert_ne!(Rng::from_seed_bytes(&[0]), Rng::from_seed_bytes(&[0, 0]));
+
+    let x = 0x0123_4567_89ab_cdef_fedc_ba98_7654_3210u128;
+    assert_eq!(
+        Rng::with_seed_128(x),
+        Rng::from_seed_bytes(&x.to_le_bytes())
+    );
+    assert_ne!(Rng::with_seed_128(x), Rng::with_seed_128(x ^ 1 << 100));
+}
+
+#[test]
+fn next_raw() {
+    let mut a = fastrand::Rng::with_seed(21);
+    let mut b = a.clone();
+
+    for _ in 0..10 {
+        assert_eq!(a.next_u32(), b.u32(..));
+        assert_eq!(a.next_u64(), b.u64(..));
+        assert_eq!(a.next_u128(), b.u128(..));
+    }
+}
+
+#[test]
+fn buffered_u8() {
+    let mut rng = fastrand::BufferedRng::new(fastrand::Rng::with_seed(4));
+
+    let mut counts = [0u32; 6];
+    for _ in 0..60_000 {
+        let x = rng.u8(10..16);
+        assert!((10..16).contains(&x));
+        counts[usize::from(x - 10)] += 1;
+    }
+    assert!(counts.iter().all(|&c| (9_500..10_500).contains(&c)));
+
+    for x in &[0u8, 255] {
+        while rng.u8(..) != *x {}
+    }
+    assert_eq!(rng.u8(7..=7), 7);
+    for x in &[false, true] {
+        while rng.bool() != *x {}
+    }
+
+    // Eight bytes come from a single output of the wrapped generator.
+    let mut rng = fastrand::BufferedRng::new(fastrand::Rng::with_seed(4));
+    let bytes: Vec<u8> = (0..8).map(|_| rng.u8(..)).collect();
+    let mut plain = fastrand::Rng::with_seed(4);
+    assert_eq!(bytes, plain.u64(..).to_le_bytes());
+    assert_eq!(rng.into_inner(), plain);
+}
+
+#[test]
+fn u64_stream() {
+    let mut rng = fastrand::Rng::with_seed(8);
+    let expected: Vec<u64> = (0..16).map(|_| rng.u64(..)).collect();
+
+    let mut stream = fastrand::Rng::with_seed(8).into_u64_stream();
+    let prefix: Vec<u64> = stream.by_ref().take(16).collect();
+    assert_eq!(prefix, expected);
+    assert_eq!(stream.into_inner(), rng);
+}
+
+#[test]
+fn bytes() {
+    let mut r = fastrand::Rng::with_seed(10);
+    let a = r.bytes(37);
+    let b = r.bytes(37);
+    assert_eq!(a.len(), 37);
+    assert_eq!(b.len(), 37);
+    assert_ne!(a, b);
+
+    let seed = r.get_seed();
+    assert!(r.bytes(0).is_empty());
+    assert_eq!(r.get_seed(), seed);
+
+    assert_eq!(fastrand::bytes(5).len(), 5);
+}
+
+#[test]
+fn choose_multiple_with_replacement() {
+    let mut r = fastrand::Rng::with_seed(13);
+    let items = ['a', 'b', 'c'];
+
+    for &k in &[0, 1, 3, 50] {
+        let sample = r.choose_multiple_with_replacement(&items, k);
+        assert_eq!(sample.len(), k);
+        assert!(sample.iter().all(|c| items.contains(c)));
+    }
+
+    // With more picks than items, duplicates are inevitable.
+    let mut sample = r.choose_multiple_with_replacement(&items, 10);
+    sample.sort_unstable();
+    sample.dedup();
+    assert!(sample.len() <= 3);
+
+    assert!(r.choose_multiple_with_replacement::<u8>(&[], 0).is_empty());
+}
+
+#[test]
+fn swap_remove_random() {
+    let mut r = fastrand::Rng::with_seed(14);
+    let mut v: Vec<u32> = (0..100).collect();
+
+    let mut removed = Vec::new();
+    while let Some(x) = r.swap_remove_random(&mut v) {
+        removed.push(x);
+    }
+    assert!(v.is_empty());
+    assert_ne!(removed, (0..100).collect::<Vec<_>>());
+
+    removed.sort_unstable();
+    assert_eq!(removed, (0..100).collect::<Vec<_>>());
+    assert_eq!(r.swap_remove_random::<u32>(&mut Vec::new()), None);
+}
+
+#[test]
+fn choose_key() {
+    use std::collections::HashMap;
+
+    let mut r = fastrand::Rng::with_seed(15);
+    let map: HashMap<u8, &str> = [(1, "one"), (2, "two"), (3, "three"), (4, "four")]
+        .iter()
+        .cloned()
+        .collect();
+
+    let mut counts = HashMap::new();
+    for _ in 0..40_000 {
+        *counts.entry(*r.choose_key(&map).unwrap()).or_insert(0) += 1;
+    }
+    assert_eq!(counts.len(), 4);
+    assert!(counts.values().all(|&c| (9_500..10_500).contains(&c)));
+
+    assert_eq!(r.choose_key(&HashMap::<u8, u8>::new()), None);
+}
+
+#[test]
+fn permutation() {
+    let mut r = fastrand::Rng::with_seed(17);
+    for &n in &[0, 1, 2, 10, 1000] {
+        let mut p = r.permutation(n);
+        assert_eq!(p.len(), n);
+        p.sort_unstable();
+        assert_eq!(p, (0..n).collect::<Vec<_>>());
+    }
+    assert_ne!(r.permutation(100), (0..100).collect::<Vec<_>>());
+    assert!(fastrand::permutation(0).is_empty());
+}
+
+#[test]
+fn fill_with() {
+    let mut a = fastrand::Rng::with_seed(18);
+    let mut b = a.clone();
+
+    let mut v = [0u16; 20];
+    a.fill_with(&mut v, |r| r.u16(..1000));
+    for &x in &v {
+        assert_eq!(x, b.u16(..1000));
+    }
+    assert_eq!(a, b);
+}
+
+#[test]
+fn rotate_random() {
+    let original: Vec<u32> = (0..10).collect();
+    let rotation = |seed| {
+        let mut v = original.clone();
+        fastrand::Rng::with_seed(seed).rotate_random(&mut v);
+        let start = v[0] as usize;
+        let expected: Vec<u32> = (0..10).map(|i| ((start + i) % 10) as u32).collect();
+        assert_eq!(v, expected);
+        start
+    };
+
+    let starts: std::collections::HashSet<usize> = (0..20).map(rotation).collect();
+    assert!(starts.len() > 1);
+
+    let mut empty: [u8; 0] = [];
+    fastrand::Rng::new().rotate_random(&mut empty);
+}
+
+#[test]
+fn split_at_random() {
+    let mut r = fastrand::Rng::with_seed(19);
+    let v: Vec<u8> = (0..10).collect();
+
+    let mut lengths = std::collections::HashSet::new();
+    for _ in 0..1000 {
+        let (a, b) = r.split_at_random(&v);
+        assert_eq!([a, b].concat(), v);
+        lengths.insert(a.len());
+    }
+    assert_eq!(lengths.len(), 11);
+
+    let mut w = v.clone();
+    let (a, b) = r.split_at_mut_random(&mut w);
+    a.iter_mut().for_each(|x| *x += 100);
+    assert_eq!(a.len() + b.len(), 10);
+
+    let empty: [u8; 0] = [];
+    assert_eq!(r.split_at_random(&empty), (&[][..], &[][..]));
+}
+
+#[test]
+fn shuffle_deque() {
+    use std::collections::VecDeque;
+
+    // Wrap the contents around the end of the buffer so they are not contiguous.
+    let mut deque = VecDeque::with_capacity(16);
+    deque.extend(0..10);
+    deque.drain(..8);
+    deque.extend(10..20);
+    assert!(!deque.as_slices().1.is_empty());
+
+    let mut r = fastrand::Rng::with_seed(9);
+    let mut expected = deque.clone();
+    r.shuffle_deque(&mut deque);
+    fastrand::Rng::with_seed(9).shuffle(expected.make_contiguous());
+    assert_eq!(deque, expected);
+
+    let mut sorted: Vec<i32> = deque.iter().copied().collect();
+    sorted.sort_unstable();
+    assert_eq!(sorted, (8..20).collect::<Vec<_>>());
+
+    fastrand::shuffle_deque(&mut deque);
+    assert_eq!(deque.len(), 12);
+}
@@ -0,0 +1,23 @@
+#[test]
+fn uuid_v7() {
+    let mut r = fastrand::Rng::new();
+
+    let a = r.uuid_v7_from_millis(0x0123_4567_89ab);
+    assert_eq!(a[..6], [0x01, 0x23, 0x45, 0x67, 0x89, 0xab]);
+    assert_eq!(a[6] >> 4, 7);
+    assert_eq!(a[8] >> 6, 0b10);
+
+    let b = r.uuid_v7_from_millis(0x0123_4567_89ac);
+    assert!(a < b);
+
+    let s = r.uuid_v7_string();
+    assert_eq!(s.len(), 36);
+    assert_eq!(s.as_bytes()[14], b'7');
+    for (i, c) in s.chars().enumerate() {
+        if i == 8 || i == 13 || i == 18 || i == 23 {
+            assert_eq!(c, '-');
+        } else {
+            assert!(c.is_ascii_hexdigit() && !c.is_ascii_uppercase());
+        }
+    }
+}
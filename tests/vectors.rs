@@ -0,0 +1,273 @@
+//! Known-answer tests for the output of `Rng`.
+//!
+//! These vectors define the stable output stream of a seeded generator: the same seed must
+//! produce the same values on every platform and in every release. If any of these tests fail,
+//! the algorithm has changed and reproducibility for users has been broken.
+
+use fastrand::Rng;
+
+struct Vector {
+    seed: u64,
+    u64s: [u64; 5],
+    u32s: [u32; 5],
+    f64s: [f64; 5],
+    chars: [char; 5],
+    u128s: [u128; 3],
+    /// Outputs of `usize(..1000)` on 64-bit targets.
+    usizes_64: [usize; 5],
+}
+
+const VECTORS: &[Vector] = &[
+    Vector {
+        seed: 0,
+        u64s: [
+            0x9a45_cd88_8d59_f0d6,
+            0x0144_5b6a_1896_63f5,
+            0x1842_218b_97e7_a496,
+            0x4dda_1bc7_277a_55f9,
+            0x120d_43ca_60ab_acb4,
+        ],
+        u32s: [
+            0x8d59_f0d6,
+            0x1896_63f5,
+            0x97e7_a496,
+            0x277a_55f9,
+            0x60ab_acb4,
+        ],
+        f64s: [
+            0.6026276071910173,
+            0.0049492963813349,
+            0.09475907953301865,
+            0.30410932171023686,
+            0.0705149048887237,
+        ],
+        chars: [
+            '\u{9668e}',
+            '\u{1a937}',
+            '\u{a19a2}',
+            '\u{2a5e3}',
+            '\u{67061}',
+        ],
+        u128s: [
+            0x9a45_cd88_8d59_f0d6_0144_5b6a_1896_63f5,
+            0x1842_218b_97e7_a496_4dda_1bc7_277a_55f9,
+            0x120d_43ca_60ab_acb4_4d8c_1a51_fabc_c9a2,
+        ],
+        usizes_64: [602, 4, 94, 304, 70],
+    },
+    Vector {
+        seed: 1,
+        u64s: [
+            0xa833_bdcd_b6d1_beb1,
+            0x88dc_97e5_aab5_fe3d,
+            0x3d58_bb03_f31a_4686,
+            0xc763_de76_4d6f_e1b0,
+            0x027a_617d_2237_a6c9,
+        ],
+        u32s: [
+            0xb6d1_beb1,
+            0xaab5_fe3d,
+            0xf31a_4686,
+            0x4d6f_e1b0,
+            0x2237_a6c9,
+        ],
+        f64s: [
+            0.657039511416605,
+            0.534615987368589,
+            0.2396351704256654,
+            0.7788676298927721,
+            0.00967988305111911,
+        ],
+        chars: [
+            '\u{c2637}',
+            '\u{b58c0}',
+            '\u{102525}',
+            '\u{52a02}',
+            '\u{24ca0}',
+        ],
+        u128s: [
+            0xa833_bdcd_b6d1_beb1_88dc_97e5_aab5_fe3d,
+            0x3d58_bb03_f31a_4686_c763_de76_4d6f_e1b0,
+            0x027a_617d_2237_a6c9_c7f4_e5c4_4c95_357a,
+        ],
+        usizes_64: [657, 534, 239, 778, 9],
+    },
+    Vector {
+        seed: 0x4d59_5df4_d0f3_3173,
+        u64s: [
+            0x2cad_31eb_a56f_9f67,
+            0x37f4_c9ad_0ba5_5486,
+            0x6614_12a3_20e1_9e59,
+            0x48a3_9026_42b5_ffbc,
+            0x7a06_10e1_f16c_26db,
+        ],
+        u32s: [
+            0xa56f_9f67,
+            0x0ba5_5486,
+            0x20e1_9e59,
+            0x42b5_ffbc,
+            0xf16c_26db,
+        ],
+        f64s: [
+            0.17451774600107073,
+            0.21857891535514296,
+            0.39874378664615606,
+            0.28374577459427264,
+            0.4766550590151586,
+        ],
+        chars: [
+            '\u{aff3e}',
+            '\u{c59d}',
+            '\u{235f4}',
+            '\u{47400}',
+            '\u{1008a3}',
+        ],
+        u128s: [
+            0x2cad_31eb_a56f_9f67_37f4_c9ad_0ba5_5486,
+            0x6614_12a3_20e1_9e59_48a3_9026_42b5_ffbc,
+            0x7a06_10e1_f16c_26db_a212_9714_0f04_1552,
+        ],
+        usizes_64: [174, 218, 398, 283, 476],
+    },
+    Vector {
+        seed: u64::MAX,
+        u64s: [
+            0x10ba_1525_e333_549e,
+            0x205f_b8bd_5917_10a9,
+            0x9fda_ea1b_39c1_49ae,
+            0x2fed_759e_1831_4395,
+            0x9ae5_3855_1694_cf7c,
+        ],
+        u32s: [
+            0xe333_549e,
+            0x5917_10a9,
+            0x39c1_49ae,
+            0x1831_4395,
+            0x1694_cf7c,
+        ],
+        f64s: [
+            0.0653393952845367,
+            0.12646059630159479,
+            0.6244341198414576,
+            0.18721709356049576,
+            0.605060120366562,
+        ],
+        chars: [
+            '\u{f174f}',
+            '\u{5efbf}',
+            '\u{3dc07}',
+            '\u{1a283}',
+            '\u{1872d}',
+        ],
+        u128s: [
+            0x10ba_1525_e333_549e_205f_b8bd_5917_10a9,
+            0x9fda_ea1b_39c1_49ae_2fed_759e_1831_4395,
+            0x9ae5_3855_1694_cf7c_904f_0d8d_1ef7_d30b,
+        ],
+        usizes_64: [65, 126, 624, 187, 605],
+    },
+];
+
+#[test]
+fn u64_vectors() {
+    for v in VECTORS {
+        let mut rng = Rng::with_seed(v.seed);
+        for &expected in &v.u64s {
+            assert_eq!(rng.u64(..), expected, "seed {:#x}", v.seed);
+        }
+    }
+}
+
+#[test]
+fn u32_vectors() {
+    for v in VECTORS {
+        let mut rng = Rng::with_seed(v.seed);
+        for &expected in &v.u32s {
+            assert_eq!(rng.u32(..), expected, "seed {:#x}", v.seed);
+        }
+    }
+}
+
+#[test]
+fn f64_vectors() {
+    for v in VECTORS {
+        let mut rng = Rng::with_seed(v.seed);
+        for &expected in &v.f64s {
+            assert_eq!(
+                rng.f64().to_bits(),
+                expected.to_bits(),
+                "seed {:#x}",
+                v.seed
+            );
+        }
+    }
+}
+
+#[test]
+fn char_vectors() {
+    for v in VECTORS {
+        let mut rng = Rng::with_seed(v.seed);
+        for &expected in &v.chars {
+            assert_eq!(rng.char(..), expected, "seed {:#x}", v.seed);
+        }
+    }
+}
+
+#[test]
+fn u128_vectors() {
+    for v in VECTORS {
+        let mut rng = Rng::with_seed(v.seed);
+        for &expected in &v.u128s {
+            assert_eq!(rng.u128(..), expected, "seed {:#x}", v.seed);
+        }
+    }
+}
+
+/// `usize` and `isize` use the `u64` generator on 64-bit targets and the `u32` generator
+/// otherwise, so their output depends on the pointer width.
+#[test]
+fn usize_vectors() {
+    for v in VECTORS {
+        let mut rng = Rng::with_seed(v.seed);
+        for i in 0..5 {
+            #[cfg(target_pointer_width = "64")]
+            assert_eq!(rng.usize(..), v.u64s[i] as usize, "seed {:#x}", v.seed);
+            #[cfg(not(target_pointer_width = "64"))]
+            assert_eq!(rng.usize(..), v.u32s[i] as usize, "seed {:#x}", v.seed);
+        }
+
+        #[cfg(target_pointer_width = "64")]
+        {
+            let mut rng = Rng::with_seed(v.seed);
+            for &expected in &v.usizes_64 {
+                assert_eq!(rng.usize(..1000), expected, "seed {:#x}", v.seed);
+            }
+        }
+
+        // Bounded and signed values follow the same generator as the full range.
+        let mut rng = Rng::with_seed(v.seed);
+        let mut reference = Rng::with_seed(v.seed);
+        for _ in 0..5 {
+            #[cfg(target_pointer_width = "64")]
+            {
+                assert_eq!(rng.usize(..1000) as u64, reference.u64(..1000));
+                assert_eq!(rng.isize(-500..500) as i64, reference.i64(-500..500));
+            }
+            #[cfg(not(target_pointer_width = "64"))]
+            {
+                assert_eq!(rng.usize(..1000) as u32, reference.u32(..1000));
+                assert_eq!(rng.isize(-500..500) as i32, reference.i32(-500..500));
+            }
+        }
+    }
+}
+
+#[test]
+fn pinned_algorithm() {
+    for v in VECTORS {
+        let mut rng = Rng::with_algorithm(v.seed, fastrand::Algorithm::Wyrand);
+        for &expected in &v.u64s {
+            assert_eq!(rng.u64(..), expected, "seed {:#x}", v.seed);
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use fastrand::{Rng, WeightedError, WeightedIndex};
+
+#[test]
+fn weighted_index_errors() {
+    assert_eq!(WeightedIndex::new(&[]).unwrap_err(), WeightedError::NoItem);
+    assert_eq!(
+        WeightedIndex::new(&[1.0, -1.0]).unwrap_err(),
+        WeightedError::InvalidWeight
+    );
+    assert_eq!(
+        WeightedIndex::new(&[1.0, f64::INFINITY]).unwrap_err(),
+        WeightedError::InvalidWeight
+    );
+    assert_eq!(
+        WeightedIndex::new_f32(&[0.0, 0.0]).unwrap_err(),
+        WeightedError::AllWeightsZero
+    );
+}
+
+#[test]
+fn weighted_index_frequencies() {
+    let weights = [1.0, 0.0, 2.0, 3.0, 4.0];
+    let dist = WeightedIndex::new(&weights).unwrap();
+
+    let mut rng = Rng::with_seed(21);
+    let n = 100_000;
+    let mut counts = [0; 5];
+    for _ in 0..n {
+        counts[dist.sample(&mut rng)] += 1;
+    }
+
+    assert_eq!(counts[1], 0);
+    for (&count, &w) in counts.iter().zip(&weights) {
+        let expected = n as f64 * w / 10.0;
+        assert!((count as f64 - expected).abs() < 0.01 * n as f64);
+    }
+}
+
+#[test]
+fn weighted_index_f32() {
+    let dist = WeightedIndex::new_f32(&[0.0, 1.5, 0.0]).unwrap();
+    let mut rng = Rng::new();
+    for _ in 0..1000 {
+        assert_eq!(dist.sample(&mut rng), 1);
+    }
+}
+
+#[test]
+fn sample_iter_weighted() {
+    let mut rng = Rng::with_seed(16);
+    let items = [('a', 1.0), ('b', 2.0), ('c', 3.0), ('d', 4.0), ('z', 0.0)];
+
+    // A single pick follows the weights exactly.
+    let mut counts = [0u32; 4];
+    for _ in 0..100_000 {
+        let picked = rng.sample_iter_weighted(items.iter().cloned(), 1);
+        counts[(picked[0] as u8 - b'a') as usize] += 1;
+    }
+    for (i, &count) in counts.iter().enumerate() {
+        let expected = 10_000.0 * (i + 1) as f64;
+        assert!(
+            (f64::from(count) - expected).abs() < 1_000.0,
+            "{:?}",
+            counts
+        );
+    }
+
+    // Heavier items are picked more often when several are picked.
+    let mut counts = [0u32; 4];
+    for _ in 0..20_000 {
+        let picked = rng.sample_iter_weighted(items.iter().cloned(), 2);
+        assert_eq!(picked.len(), 2);
+        assert_ne!(picked[0], picked[1]);
+        for c in picked {
+            counts[(c as u8 - b'a') as usize] += 1;
+        }
+    }
+    assert!(counts.windows(2).all(|w| w[0] < w[1]), "{:?}", counts);
+
+    assert_eq!(rng.sample_iter_weighted(items.iter().cloned(), 10).len(), 4);
+    assert!(rng
+        .sample_iter_weighted(items.iter().cloned(), 0)
+        .is_empty());
+}
+
+#[test]
+#[should_panic]
+fn sample_iter_weighted_negative() {
+    let mut rng = Rng::with_seed(16);
+    rng.sample_iter_weighted(vec![(1, 1.0), (2, -1.0)], 1);
+}
+
+#[test]
+fn weighted_index_update() {
+    let mut dist = WeightedIndex::new(&[1.0, 1.0, 2.0]).unwrap();
+    let mut rng = Rng::with_seed(22);
+    let n = 100_000;
+
+    let mut counts = [0; 3];
+    for _ in 0..n {
+        counts[dist.sample(&mut rng)] += 1;
+    }
+    assert!((counts[2] as f64 - 0.5 * n as f64).abs() < 0.01 * n as f64);
+
+    // Shift most of the weight onto the first index.
+    dist.update(0, 6.0).unwrap();
+    dist.update(2, 0.0).unwrap();
+    let mut counts = [0; 3];
+    for _ in 0..n {
+        counts[dist.sample(&mut rng)] += 1;
+    }
+    assert_eq!(counts[2], 0);
+    assert!((counts[0] as f64 - 6.0 / 7.0 * n as f64).abs() < 0.01 * n as f64);
+
+    // Invalid updates are rejected and leave the distribution as it was.
+    assert_eq!(dist.update(1, -1.0), Err(WeightedError::InvalidWeight));
+    assert_eq!(dist.update(1, f64::NAN), Err(WeightedError::InvalidWeight));
+    dist.update(1, 0.0).unwrap();
+    assert_eq!(dist.update(0, 0.0), Err(WeightedError::AllWeightsZero));
+    for _ in 0..1000 {
+        assert_eq!(dist.sample(&mut rng), 0);
+    }
+}
+
+#[test]
+fn weighted_index_update_f32() {
+    let mut dist = WeightedIndex::new_f32(&[1.0, 0.0]).unwrap();
+    let mut rng = Rng::with_seed(23);
+
+    // The weight is stored as `f32`, so this overflows to infinity.
+    assert_eq!(dist.update(1, 1e300), Err(WeightedError::InvalidWeight));
+    dist.update(1, 1.0).unwrap();
+    dist.update(0, 0.0).unwrap();
+    for _ in 0..1000 {
+        assert_eq!(dist.sample(&mut rng), 1);
+    }
+}
+
+#[test]
+#[should_panic]
+fn weighted_index_update_out_of_bounds() {
+    let mut dist = WeightedIndex::new(&[1.0]).unwrap();
+    let _ = dist.update(1, 1.0);
+}